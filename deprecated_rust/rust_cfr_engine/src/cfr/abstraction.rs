@@ -1,19 +1,197 @@
 /// Gestionnaire d'abstraction pour CFR
 use crate::types::*;
+use crate::cfr::hand_eval;
 use std::collections::HashMap;
 use ahash::AHasher;
 use std::hash::{Hash, Hasher};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::time::{Duration, Instant};
+
+/// Nombre de bins de l'histogramme d'équité "potential-aware" (cf.
+/// `equity_histogram`).
+const EQUITY_HISTOGRAM_BINS: usize = 10;
+
+/// Nombre d'adversaires tirés par runout pour estimer l'équité
+/// conditionnelle à ce runout dans `equity_histogram`.
+const OPPONENTS_PER_RUNOUT: usize = 20;
+
+/// Graine fixe de la table Zobrist: la table n'a besoin d'être générée
+/// qu'une fois par processus, pas reproductible entre runs différents n'a
+/// pas d'importance tant qu'elle est stable pendant la vie du manager.
+const ZOBRIST_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Table Zobrist (rang, couleur) -> `u64` aléatoire, générée une fois à la
+/// construction. Hacher une main revient à XORer les clés de ses cartes:
+/// l'ordre n'importe pas et hole+board se combinent par simple XOR, ce qui
+/// évite de réallouer et retrier un `Vec<Card>` à chaque lookup.
+struct ZobristHasher {
+    table: [[u64; 4]; 13],
+}
+
+impl ZobristHasher {
+    fn new(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut table = [[0u64; 4]; 13];
+        for rank_row in table.iter_mut() {
+            for suit_slot in rank_row.iter_mut() {
+                *suit_slot = rng.gen();
+            }
+        }
+        Self { table }
+    }
+
+    /// Clé Zobrist d'une carte seule, combinable par XOR avec d'autres.
+    fn card_key(&self, card: Card) -> u64 {
+        self.table[(card.rank - 2) as usize][card.suit as usize]
+    }
+
+    /// Clé Zobrist d'un ensemble de cartes, indépendante de leur ordre.
+    fn hand_key(&self, cards: &[Card]) -> u64 {
+        cards.iter().fold(0u64, |key, &card| key ^ self.card_key(card))
+    }
+}
+
+/// Action réelle observée à la table telle que retranscrite depuis
+/// l'historique de mise du state: fold/check/call explicites, un montant de
+/// mise exprimé en fraction du pot, ou all-in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RawAction {
+    Fold,
+    Check,
+    Call,
+    Bet(f64),
+    AllIn,
+}
+
+/// Action projetée sur le menu discret de `BettingAbstraction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbstractAction {
+    Fold,
+    Check,
+    Call,
+    /// Index dans `BettingAbstraction::pot_fractions`.
+    Bet(usize),
+    AllIn,
+}
+
+/// Arbre de mise abstrait: chaque `RawAction` réelle est projetée sur
+/// l'entrée la plus proche d'un menu discret configurable (fold / check /
+/// call / fractions de pot / all-in), ce qui borne la taille de l'arbre de
+/// jeu au prix d'une résolution plus grossière sur la taille des mises.
+/// `encode` transforme ensuite une séquence ordonnée d'actions abstraites en
+/// un code injectif: deux lignes distinctes (jusqu'à `max_depth` actions)
+/// ne peuvent jamais collider.
+pub struct BettingAbstraction {
+    pot_fractions: Vec<f64>,
+    max_depth: usize,
+}
+
+impl BettingAbstraction {
+    pub fn new(pot_fractions: Vec<f64>, max_depth: usize) -> Self {
+        Self { pot_fractions, max_depth }
+    }
+
+    /// Menu par défaut: fold/check/call implicites plus les fractions de
+    /// pot usuelles {1/3, 1/2, 3/4, pot, 1.5x pot} et all-in, profondeur
+    /// capée à 8 actions par street.
+    pub fn default_menu() -> Self {
+        Self::new(vec![0.33, 0.5, 0.75, 1.0, 1.5], 8)
+    }
+
+    /// Taille de l'alphabet de tokens abstraits: fold, check, call, une
+    /// entrée par fraction de pot, plus all-in.
+    fn alphabet_size(&self) -> usize {
+        3 + self.pot_fractions.len() + 1
+    }
+
+    /// Projette une action réelle sur l'entrée du menu la plus proche
+    /// (distance absolue sur la fraction de pot misée).
+    pub fn abstract_action(&self, action: RawAction) -> AbstractAction {
+        match action {
+            RawAction::Fold => AbstractAction::Fold,
+            RawAction::Check => AbstractAction::Check,
+            RawAction::Call => AbstractAction::Call,
+            RawAction::AllIn => AbstractAction::AllIn,
+            RawAction::Bet(fraction) => {
+                let nearest = self
+                    .pot_fractions
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| {
+                        (**a - fraction).abs().partial_cmp(&(**b - fraction).abs()).unwrap()
+                    })
+                    .map(|(idx, _)| idx)
+                    .unwrap_or(0);
+                AbstractAction::Bet(nearest)
+            }
+        }
+    }
+
+    /// Index du token abstrait dans `[0, alphabet_size())`: fold=0, check=1,
+    /// call=2, bet-fraction `i` -> `3 + i`, all-in -> dernier index.
+    fn token(&self, action: AbstractAction) -> usize {
+        match action {
+            AbstractAction::Fold => 0,
+            AbstractAction::Check => 1,
+            AbstractAction::Call => 2,
+            AbstractAction::Bet(idx) => 3 + idx,
+            AbstractAction::AllIn => self.alphabet_size() - 1,
+        }
+    }
+
+    /// Encode la séquence ordonnée d'actions (tronquée à `max_depth`) en un
+    /// entier positionnel en base `alphabet_size() + 1`, où chaque token est
+    /// décalé de 1 avant d'être accumulé: ce décalage fait que deux
+    /// séquences de longueurs différentes ne peuvent jamais produire le
+    /// même code (contrairement à une simple concaténation en base
+    /// `alphabet_size()`, où des zéros de tête seraient invisibles).
+    pub fn encode(&self, actions: &[RawAction]) -> Vec<u8> {
+        let base = (self.alphabet_size() + 1) as u64;
+        let mut code = 0u64;
+        for &action in actions.iter().take(self.max_depth) {
+            let token = self.token(self.abstract_action(action)) as u64;
+            code = code * base + token + 1;
+        }
+        code.to_be_bytes().to_vec()
+    }
+}
 
 pub struct AbstractionManager {
     pub num_buckets: usize,
-    hand_strength_cache: HashMap<Vec<Card>, f64>,
+    /// Nombre de runouts Monte Carlo échantillonnés par `expected_hand_strength`
+    /// et `equity_histogram`.
+    num_samples: usize,
+    /// Si activé, bucketer sur l'EHS potential-aware (histogramme d'équité
+    /// sur les runouts) plutôt que sur la force de main instantanée.
+    use_potential_aware: bool,
+    betting_abstraction: BettingAbstraction,
+    zobrist: ZobristHasher,
+    /// Cache de force de main keyé par clé Zobrist (XOR-fold des cartes),
+    /// plus compact et moins coûteux à hacher qu'un `Vec<Card>`.
+    hand_strength_cache: HashMap<u64, f64>,
+    /// Table hand -> bucket construite hors-ligne par `build_buckets` à
+    /// partir d'un clustering EMD k-means des histogrammes d'équité; vide
+    /// tant qu'elle n'a pas été appelée, auquel cas `abstract_cards` retombe
+    /// sur le bucketing linéaire par force de main.
+    bucket_lookup: HashMap<Vec<Card>, usize>,
 }
 
 impl AbstractionManager {
-    pub fn new(num_buckets: usize) -> Self {
+    pub fn new(
+        num_buckets: usize,
+        num_samples: usize,
+        use_potential_aware: bool,
+        betting_abstraction: BettingAbstraction,
+    ) -> Self {
         Self {
             num_buckets,
+            num_samples,
+            use_potential_aware,
+            betting_abstraction,
+            zobrist: ZobristHasher::new(ZOBRIST_SEED),
             hand_strength_cache: HashMap::new(),
+            bucket_lookup: HashMap::new(),
         }
     }
 
@@ -21,56 +199,83 @@ impl AbstractionManager {
     pub fn state_to_infoset(&self, state: &PokerState) -> InformationSet {
         // Hash des cartes abstraites
         let abstracted_cards = self.abstract_cards(&state.hole_cards, &state.community_cards);
-        
+
         // Séquence de mise simplifiée
         let betting_sequence = self.abstract_betting_sequence(state);
-        
+
+        // Histogramme d'équité potential-aware (cf. `equity_histogram`),
+        // uniquement calculé si le mode est activé: il est coûteux (N
+        // runouts x M adversaires) et redondant avec `abstracted_cards`
+        // sinon.
+        let equity_histogram = if self.use_potential_aware {
+            Some(self.equity_histogram(&state.hole_cards, &state.community_cards))
+        } else {
+            None
+        };
+
         InformationSet {
             abstracted_cards,
             betting_sequence,
             position: state.position as u8,
             round: state.betting_round.clone(),
+            equity_histogram,
         }
     }
 
     /// Abstraction des cartes en buckets
     fn abstract_cards(&self, hole_cards: &[Card], community_cards: &[Card]) -> u64 {
         let mut hasher = AHasher::default();
-        
-        // Évaluer force de main
-        let hand_strength = self.evaluate_hand_strength(hole_cards, community_cards);
-        
-        // Bucketing basé sur force de main
-        let bucket = ((hand_strength * self.num_buckets as f64) as usize).min(self.num_buckets - 1);
-        
+
+        // Précalculer une seule fois le set de cartes et sa clé Zobrist,
+        // partagés par le bucketing, le cache de force de main et les
+        // informations suits/straights ci-dessous.
+        let mut all_cards = hole_cards.to_vec();
+        all_cards.extend_from_slice(community_cards);
+        let zobrist_key = self.zobrist.hand_key(&all_cards);
+
+        // Bucket data-driven si `build_buckets` a déjà clusterisé cette
+        // main, sinon retomber sur le seuil linéaire par force de main.
+        let bucket = if let Some(&bucket) = self.bucket_lookup.get(&all_cards) {
+            bucket
+        } else {
+            let hand_strength = if self.use_potential_aware {
+                self.expected_hand_strength(hole_cards, community_cards)
+            } else {
+                self.evaluate_hand_strength(hole_cards, community_cards, zobrist_key)
+            };
+            ((hand_strength * self.num_buckets as f64) as usize).min(self.num_buckets - 1)
+        };
+
         // Hash du bucket + cartes importantes
         bucket.hash(&mut hasher);
-        
+
         // Ajouter informations sur les suits (pour flush draws)
-        let suit_info = self.get_suit_information(hole_cards, community_cards);
+        let suit_info = self.get_suit_information(&all_cards);
         suit_info.hash(&mut hasher);
-        
+
         // Ajouter informations sur les straights
-        let straight_info = self.get_straight_information(hole_cards, community_cards);
+        let straight_info = self.get_straight_information(&all_cards);
         straight_info.hash(&mut hasher);
-        
+
         hasher.finish()
     }
 
-    /// Évaluation simplifiée de force de main
-    fn evaluate_hand_strength(&self, hole_cards: &[Card], community_cards: &[Card]) -> f64 {
+    /// Évaluation simplifiée de force de main, cachée par clé Zobrist
+    /// (`key`, précalculée par l'appelant sur le même set de cartes) plutôt
+    /// que par le `Vec<Card>` complet.
+    fn evaluate_hand_strength(&self, hole_cards: &[Card], community_cards: &[Card], key: u64) -> f64 {
         if hole_cards.is_empty() {
             return 0.0;
         }
 
-        let mut all_cards = hole_cards.to_vec();
-        all_cards.extend_from_slice(community_cards);
-        
         // Cache lookup
-        if let Some(&cached_strength) = self.hand_strength_cache.get(&all_cards) {
+        if let Some(&cached_strength) = self.hand_strength_cache.get(&key) {
             return cached_strength;
         }
 
+        let mut all_cards = hole_cards.to_vec();
+        all_cards.extend_from_slice(community_cards);
+
         let strength = match community_cards.len() {
             0 => self.evaluate_preflop(hole_cards),
             3..=5 => self.evaluate_postflop(&all_cards),
@@ -80,6 +285,339 @@ impl AbstractionManager {
         strength.clamp(0.0, 1.0)
     }
 
+    /// EHS (Expected Hand Strength) Monte Carlo: moyenne de
+    /// `equity_histogram` pondérée par le centre de chaque bin, donc une
+    /// équité quantifiée à la largeur d'un bin (`1 / EQUITY_HISTOGRAM_BINS`)
+    /// près, qui réutilise le même histogramme que le mode potential-aware
+    /// au lieu de tirer deux fois.
+    pub fn expected_hand_strength(&self, hole_cards: &[Card], community_cards: &[Card]) -> f64 {
+        let histogram = self.equity_histogram(hole_cards, community_cards);
+        Self::histogram_to_ehs(&histogram)
+    }
+
+    /// Réduit un histogramme d'équité normalisé à son espérance scalaire
+    /// (milieu de bin pondéré par la fréquence), factorisé hors de
+    /// `expected_hand_strength` afin que `anneal_buckets` puisse réutiliser
+    /// des histogrammes déjà échantillonnés sans retirer de runouts.
+    fn histogram_to_ehs(histogram: &[f64]) -> f64 {
+        histogram
+            .iter()
+            .enumerate()
+            .map(|(bin, &frequency)| frequency * (bin as f64 + 0.5) / EQUITY_HISTOGRAM_BINS as f64)
+            .sum()
+    }
+
+    /// Histogramme "potential-aware" de l'équité: échantillonne
+    /// `self.num_samples` runouts complétant `community_cards` jusqu'à 5
+    /// cartes, estime l'équité conditionnelle à chaque runout sur
+    /// `OPPONENTS_PER_RUNOUT` mains adverses tirées d'un sabot excluant les
+    /// cartes déjà connues, puis place cette équité dans un des
+    /// `EQUITY_HISTOGRAM_BINS` bins. Contrairement au scalaire EHS, deux
+    /// mains de même équité moyenne mais de formes de distribution
+    /// différentes (tirage couleur bimodal vs paire moyenne unimodale)
+    /// donnent des histogrammes distincts.
+    pub fn equity_histogram(&self, hole_cards: &[Card], community_cards: &[Card]) -> Vec<f64> {
+        let mut histogram = vec![0.0f64; EQUITY_HISTOGRAM_BINS];
+        if hole_cards.len() < 2 {
+            return histogram;
+        }
+
+        let mut rng = thread_rng();
+        let missing_community = 5 - community_cards.len().min(5);
+
+        for _ in 0..self.num_samples.max(1) {
+            let mut known = hole_cards.to_vec();
+            known.extend_from_slice(community_cards);
+            let mut deck = self.remaining_deck(&known);
+            deck.shuffle(&mut rng);
+
+            let runout = deck.split_off(deck.len() - missing_community);
+            let mut board = community_cards.to_vec();
+            board.extend_from_slice(&runout);
+
+            let mut hero_cards = hole_cards.to_vec();
+            hero_cards.extend_from_slice(&board);
+            let hero_rank = hand_eval::hand_rank_bits(&hero_cards);
+
+            let mut wins = 0.0;
+            for _ in 0..OPPONENTS_PER_RUNOUT {
+                deck.shuffle(&mut rng);
+                let mut villain_cards = deck[deck.len() - 2..].to_vec();
+                villain_cards.extend_from_slice(&board);
+                let villain_rank = hand_eval::hand_rank_bits(&villain_cards);
+
+                wins += match hero_rank.cmp(&villain_rank) {
+                    std::cmp::Ordering::Greater => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Less => 0.0,
+                };
+            }
+
+            let runout_equity = wins / OPPONENTS_PER_RUNOUT as f64;
+            let bin = ((runout_equity * EQUITY_HISTOGRAM_BINS as f64) as usize)
+                .min(EQUITY_HISTOGRAM_BINS - 1);
+            histogram[bin] += 1.0;
+        }
+
+        let total: f64 = histogram.iter().sum();
+        if total > 0.0 {
+            for count in &mut histogram {
+                *count /= total;
+            }
+        }
+        histogram
+    }
+
+    /// Sabot restant (52 cartes moins `known`), utilisé pour tirer
+    /// adversaires et runouts sans jamais redistribuer une carte connue.
+    fn remaining_deck(&self, known: &[Card]) -> Vec<Card> {
+        let mut deck = Vec::with_capacity(52);
+        for suit in 0u8..4 {
+            for rank in 2u8..=14 {
+                let card = Card { rank, suit };
+                if !known.contains(&card) {
+                    deck.push(card);
+                }
+            }
+        }
+        deck
+    }
+
+    /// Construit l'abstraction de cartes data-driven: regroupe `hands`
+    /// (mains canoniques d'une street, chacune avec son histogramme
+    /// d'équité pré-échantillonné via `equity_histogram`) en
+    /// `self.num_buckets` clusters par k-means utilisant l'EMD (Earth
+    /// Mover's Distance) comme métrique. L'EMD 1-D entre deux distributions
+    /// normalisées égale la distance L1 entre leurs CDF (fonctions de
+    /// répartition cumulées, cf. `cdf`/`cdf_l1`), ce qui permet un k-means
+    /// standard sur les CDF sans jamais calculer de plan de transport
+    /// explicite. Persiste le résultat dans `bucket_lookup`, que
+    /// `abstract_cards` consulte ensuite en O(1) au lieu de recalculer un
+    /// seuil linéaire.
+    pub fn build_buckets(&mut self, hands: &[(Vec<Card>, Vec<f64>)]) {
+        if hands.is_empty() || self.num_buckets == 0 {
+            return;
+        }
+
+        let cdfs: Vec<Vec<f64>> = hands.iter().map(|(_, hist)| Self::cdf(hist)).collect();
+        let k = self.num_buckets.min(cdfs.len());
+        let mut centroids = Self::kmeans_plus_plus_init(&cdfs, k);
+        let mut assignments = vec![0usize; cdfs.len()];
+
+        loop {
+            let mut changed = false;
+            for (i, cdf) in cdfs.iter().enumerate() {
+                let nearest = centroids
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| {
+                        Self::cdf_l1(cdf, a).partial_cmp(&Self::cdf_l1(cdf, b)).unwrap()
+                    })
+                    .map(|(idx, _)| idx)
+                    .unwrap_or(0);
+                if assignments[i] != nearest {
+                    assignments[i] = nearest;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+
+            for (cluster, centroid) in centroids.iter_mut().enumerate() {
+                let members: Vec<&Vec<f64>> = cdfs
+                    .iter()
+                    .zip(assignments.iter())
+                    .filter(|(_, &c)| c == cluster)
+                    .map(|(cdf, _)| cdf)
+                    .collect();
+                if members.is_empty() {
+                    continue;
+                }
+
+                let mut mean = vec![0.0; centroid.len()];
+                for member in &members {
+                    for (bin, value) in member.iter().enumerate() {
+                        mean[bin] += value;
+                    }
+                }
+                for value in &mut mean {
+                    *value /= members.len() as f64;
+                }
+                *centroid = mean;
+            }
+        }
+
+        self.bucket_lookup = hands
+            .iter()
+            .zip(assignments.iter())
+            .map(|((hand, _), &bucket)| (hand.clone(), bucket))
+            .collect();
+    }
+
+    /// Recuit simulé sur les affectations main -> bucket, alternative à
+    /// `build_buckets` qui optimise directement la variance intra-bucket de
+    /// l'EHS plutôt que l'EMD entre histogrammes. Part de `bucket_lookup`
+    /// s'il a déjà été peuplé (par un appel précédent ou par
+    /// `build_buckets`), sinon d'une répartition round-robin. Tourne jusqu'à
+    /// épuisement de `time_budget` (borne en temps réel, pas en nombre
+    /// d'itérations fixe) plutôt qu'un critère de convergence, puisque le
+    /// nombre de coups nécessaires dépend fortement de la taille de `hands`.
+    /// La température décroît géométriquement de `t0` vers `t0 * cooling` à
+    /// mesure que le budget s'épuise; `cooling` est donc le ratio
+    /// température finale / température initiale, pas un facteur par coup.
+    pub fn anneal_buckets(
+        &mut self,
+        hands: &[(Vec<Card>, Vec<f64>)],
+        t0: f64,
+        cooling: f64,
+        time_budget: Duration,
+    ) {
+        if hands.is_empty() || self.num_buckets == 0 {
+            return;
+        }
+
+        let k = self.num_buckets.min(hands.len());
+        let ehs: Vec<f64> = hands.iter().map(|(_, hist)| Self::histogram_to_ehs(hist)).collect();
+        let mut rng = thread_rng();
+
+        let mut assignment: Vec<usize> = hands
+            .iter()
+            .enumerate()
+            .map(|(i, (hand, _))| self.bucket_lookup.get(hand).copied().unwrap_or(i % k))
+            .collect();
+
+        // Sommes et sommes des carrés par bucket: la variance d'un bucket se
+        // dérive de ces deux accumulateurs en O(1) (`E[X^2] - E[X]^2`), ce
+        // qui permet de recalculer le delta de score d'un coup sans jamais
+        // reparcourir les mains des deux buckets affectés.
+        let mut sums = vec![0.0f64; k];
+        let mut sq_sums = vec![0.0f64; k];
+        let mut counts = vec![0usize; k];
+        for (i, &bucket) in assignment.iter().enumerate() {
+            sums[bucket] += ehs[i];
+            sq_sums[bucket] += ehs[i] * ehs[i];
+            counts[bucket] += 1;
+        }
+
+        let variance = |sum: f64, sq_sum: f64, count: usize| -> f64 {
+            if count == 0 {
+                return 0.0;
+            }
+            let mean = sum / count as f64;
+            (sq_sum / count as f64 - mean * mean).max(0.0)
+        };
+
+        let mut score: f64 = (0..k).map(|b| variance(sums[b], sq_sums[b], counts[b])).sum();
+        let mut best_assignment = assignment.clone();
+        let mut best_score = score;
+
+        let start = Instant::now();
+        let budget_secs = time_budget.as_secs_f64().max(f64::EPSILON);
+        while start.elapsed() < time_budget {
+            let progress = (start.elapsed().as_secs_f64() / budget_secs).min(1.0);
+            let temperature = t0 * cooling.powf(progress);
+
+            let hand_idx = rng.gen_range(0..hands.len());
+            let old_bucket = assignment[hand_idx];
+            let new_bucket = rng.gen_range(0..k);
+            if new_bucket == old_bucket {
+                continue;
+            }
+
+            let value = ehs[hand_idx];
+            let before = variance(sums[old_bucket], sq_sums[old_bucket], counts[old_bucket])
+                + variance(sums[new_bucket], sq_sums[new_bucket], counts[new_bucket]);
+            let after = variance(sums[old_bucket] - value, sq_sums[old_bucket] - value * value, counts[old_bucket] - 1)
+                + variance(sums[new_bucket] + value, sq_sums[new_bucket] + value * value, counts[new_bucket] + 1);
+            let delta = after - before;
+
+            let accept = delta < 0.0 || rng.gen::<f64>() < (-delta / temperature).exp();
+            if !accept {
+                continue;
+            }
+
+            sums[old_bucket] -= value;
+            sq_sums[old_bucket] -= value * value;
+            counts[old_bucket] -= 1;
+            sums[new_bucket] += value;
+            sq_sums[new_bucket] += value * value;
+            counts[new_bucket] += 1;
+            assignment[hand_idx] = new_bucket;
+            score += delta;
+
+            if score < best_score {
+                best_score = score;
+                best_assignment = assignment.clone();
+            }
+        }
+
+        self.bucket_lookup = hands
+            .iter()
+            .zip(best_assignment.iter())
+            .map(|((hand, _), &bucket)| (hand.clone(), bucket))
+            .collect();
+    }
+
+    /// CDF (cumulée, non décroissante) d'un histogramme normalisé.
+    fn cdf(histogram: &[f64]) -> Vec<f64> {
+        let mut running = 0.0;
+        histogram
+            .iter()
+            .map(|&frequency| {
+                running += frequency;
+                running
+            })
+            .collect()
+    }
+
+    /// Distance L1 entre deux CDF, égale à l'EMD 1-D entre les histogrammes
+    /// dont elles dérivent.
+    fn cdf_l1(a: &[f64], b: &[f64]) -> f64 {
+        a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum()
+    }
+
+    /// Initialisation k-means++: le premier centroïde est tiré
+    /// uniformément, les suivants avec une probabilité proportionnelle au
+    /// carré de la distance CDF-L1 au centroïde le plus proche déjà choisi.
+    fn kmeans_plus_plus_init(cdfs: &[Vec<f64>], k: usize) -> Vec<Vec<f64>> {
+        let mut rng = thread_rng();
+        let mut centroids = vec![cdfs[rng.gen_range(0..cdfs.len())].clone()];
+
+        while centroids.len() < k {
+            let weights: Vec<f64> = cdfs
+                .iter()
+                .map(|cdf| {
+                    centroids
+                        .iter()
+                        .map(|centroid| Self::cdf_l1(cdf, centroid))
+                        .fold(f64::INFINITY, f64::min)
+                        .powi(2)
+                })
+                .collect();
+
+            let total: f64 = weights.iter().sum();
+            if total <= 0.0 {
+                centroids.push(cdfs[rng.gen_range(0..cdfs.len())].clone());
+                continue;
+            }
+
+            let mut pick = rng.gen_range(0.0..total);
+            let mut chosen = weights.len() - 1;
+            for (idx, &weight) in weights.iter().enumerate() {
+                if pick < weight {
+                    chosen = idx;
+                    break;
+                }
+                pick -= weight;
+            }
+            centroids.push(cdfs[chosen].clone());
+        }
+
+        centroids
+    }
+
     /// Évaluation preflop basée sur ranges
     fn evaluate_preflop(&self, hole_cards: &[Card]) -> f64 {
         if hole_cards.len() < 2 {
@@ -135,59 +673,13 @@ impl AbstractionManager {
         strength.clamp(0.0, 1.0)
     }
 
-    /// Évaluation postflop (simplifiée)
+    /// Évaluation postflop: meilleure main de `all_cards` via l'évaluateur
+    /// bit-packé `hand_eval` (sélection exacte best-5-de-7, kickers inclus),
+    /// normalisée en `[0, 1]` par division par `MAX_HAND_VALUE`, la borne
+    /// haute de l'encodage packé (une quinte couleur royale reste sous 1).
     fn evaluate_postflop(&self, all_cards: &[Card]) -> f64 {
-        let mut strength = 0.0;
-
-        // Vérifier paires, brelans, etc.
-        let ranks = self.count_ranks(all_cards);
-        let suits = self.count_suits(all_cards);
-
-        // Paires et mieux
-        let mut pairs = 0;
-        let mut trips = 0;
-        let mut quads = 0;
-        
-        for &count in ranks.values() {
-            match count {
-                2 => pairs += 1,
-                3 => trips += 1,
-                4 => quads += 1,
-                _ => {}
-            }
-        }
-
-        // Scoring par type de main
-        if quads > 0 {
-            strength = 0.95; // Four of a kind
-        } else if trips > 0 && pairs > 0 {
-            strength = 0.90; // Full house
-        } else if self.has_flush(&suits) {
-            strength = 0.85; // Flush
-        } else if self.has_straight(all_cards) {
-            strength = 0.80; // Straight
-        } else if trips > 0 {
-            strength = 0.75; // Three of a kind
-        } else if pairs >= 2 {
-            strength = 0.65; // Two pair
-        } else if pairs == 1 {
-            strength = 0.45; // One pair
-        } else {
-            // High card
-            let highest = all_cards.iter().map(|c| c.rank).max().unwrap_or(2);
-            strength = (highest as f64 - 2.0) / 12.0 * 0.3;
-        }
-
-        strength
-    }
-
-    /// Compter occurrences de chaque rang
-    fn count_ranks(&self, cards: &[Card]) -> HashMap<u8, usize> {
-        let mut ranks = HashMap::new();
-        for card in cards {
-            *ranks.entry(card.rank).or_insert(0) += 1;
-        }
-        ranks
+        let rank = crate::cfr::hand_eval::hand_rank_bits(all_cards);
+        rank as f64 / crate::cfr::hand_eval::MAX_HAND_VALUE as f64
     }
 
     /// Compter occurrences de chaque couleur
@@ -199,11 +691,6 @@ impl AbstractionManager {
         suits
     }
 
-    /// Vérifier présence de flush
-    fn has_flush(&self, suits: &HashMap<u8, usize>) -> bool {
-        suits.values().any(|&count| count >= 5)
-    }
-
     /// Vérifier présence de straight
     fn has_straight(&self, cards: &[Card]) -> bool {
         let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank).collect();
@@ -233,29 +720,26 @@ impl AbstractionManager {
     }
 
     /// Information sur les couleurs (flush draws)
-    fn get_suit_information(&self, hole_cards: &[Card], community_cards: &[Card]) -> u32 {
-        let all_cards = [hole_cards, community_cards].concat();
-        let suits = self.count_suits(&all_cards);
-        
+    fn get_suit_information(&self, all_cards: &[Card]) -> u32 {
+        let suits = self.count_suits(all_cards);
+
         // Encoder informations flush/flush draw
         let max_suit_count = suits.values().max().unwrap_or(&0);
         match max_suit_count {
             5.. => 4, // Flush made
             4 => 3,   // Flush draw
-            3 => 2,   // Backdoor flush draw  
+            3 => 2,   // Backdoor flush draw
             2 => 1,   // Suited hole cards
             _ => 0,   // No flush potential
         }
     }
 
     /// Information sur les straights
-    fn get_straight_information(&self, hole_cards: &[Card], community_cards: &[Card]) -> u32 {
-        let all_cards = [hole_cards, community_cards].concat();
-        
-        if self.has_straight(&all_cards) {
+    fn get_straight_information(&self, all_cards: &[Card]) -> u32 {
+        if self.has_straight(all_cards) {
             return 4; // Straight made
         }
-        
+
         // Vérifier straight draws (simplifié)
         let mut ranks: Vec<u8> = all_cards.iter().map(|c| c.rank).collect();
         ranks.sort_unstable();
@@ -283,19 +767,11 @@ impl AbstractionManager {
         0 // No straight potential
     }
 
-    /// Simplifier séquence de mise
+    /// Encoder la séquence de mise via le menu discret de
+    /// `self.betting_abstraction`, qui préserve l'ordre des actions et la
+    /// taille relative de chaque mise plutôt que de les collapser en un
+    /// simple ratio pot/stack.
     fn abstract_betting_sequence(&self, state: &PokerState) -> Vec<u8> {
-        // Pour l'instant, séquence très simple basée sur pot size ratio
-        let pot_ratio = if state.stack_size > 0.0 {
-            (state.pot_size / state.stack_size * 10.0) as u8
-        } else {
-            255 // All-in
-        };
-        
-        vec![
-            state.betting_round as u8,
-            pot_ratio.min(10), // Clamp à 10 pour limiter explosion
-            state.position as u8 % 10, // Position relative
-        ]
+        self.betting_abstraction.encode(&state.action_history)
     }
 }
\ No newline at end of file
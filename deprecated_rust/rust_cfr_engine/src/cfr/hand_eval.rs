@@ -0,0 +1,213 @@
+/// Évaluateur de main bit-packé pour `AbstractionManager::evaluate_postflop`.
+///
+/// Inspiré de l'algorithme Cactus Kev: chaque carte alimente un masque de
+/// présence par couleur (13 bits, un par rang 2..=14) pour détecter les
+/// flush/quintes couleur par simple comptage de bits, et les mains sans
+/// couleur se résolvent via une table de hachage parfaite indexée par le
+/// produit de nombres premiers associés aux rangs (la factorisation unique
+/// d'un entier détermine sans collision le multi-ensemble de rangs qui l'a
+/// produit). Les deux tables sont précalculées paresseusement: la table
+/// flush couvre les 1287 masques à 5 bits posés, la table sans-couleur les
+/// 6175 multi-ensembles de 5 rangs distincts par structure (paire, brelan,
+/// carré...), pour un total de 7462 classes d'équivalence de mains à 5
+/// cartes.
+use crate::types::Card;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+const HIGH_CARD: u32 = 0;
+const ONE_PAIR: u32 = 1;
+const TWO_PAIR: u32 = 2;
+const THREE_OF_A_KIND: u32 = 3;
+const STRAIGHT: u32 = 4;
+const FLUSH: u32 = 5;
+const FULL_HOUSE: u32 = 6;
+const FOUR_OF_A_KIND: u32 = 7;
+const STRAIGHT_FLUSH: u32 = 8;
+
+/// Nombres premiers associés aux rangs 2..=14 (index 0 = rang 2).
+const RANK_PRIMES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Valeur packée maximale ((STRAIGHT_FLUSH << 20) | 0xFFFFF), utilisée pour
+/// normaliser le rang entier en force de main dans `[0, 1]`.
+pub const MAX_HAND_VALUE: u32 = (STRAIGHT_FLUSH << 20) | 0xF_FFFF;
+
+/// Packe une catégorie (0..=8) et jusqu'à 5 rangs départageurs (4 bits
+/// chacun) en un seul entier directement comparable entre deux mains.
+fn pack(category: u32, ranks: &[u8]) -> u32 {
+    let mut tiebreak = 0u32;
+    for &rank in ranks.iter().take(5) {
+        tiebreak = (tiebreak << 4) | rank as u32;
+    }
+    (category << 20) | tiebreak
+}
+
+fn prime_product(ranks: &[u8]) -> u64 {
+    ranks.iter().map(|&rank| RANK_PRIMES[rank as usize]).product()
+}
+
+/// Cherche la quinte la plus haute parmi des rangs distincts triés
+/// croissants (gère la roue A-2-3-4-5 dont l'as compte bas).
+fn best_straight_value(distinct_ranks: &[u8]) -> Option<u32> {
+    let mut best: Option<u8> = None;
+    for window in distinct_ranks.windows(5) {
+        if window.windows(2).all(|w| w[1] == w[0] + 1) {
+            best = Some(window[4]);
+        }
+    }
+    if [0u8, 1, 2, 3, 12].iter().all(|r| distinct_ranks.contains(r)) {
+        best = best.max(Some(3));
+    }
+    best.map(|high| pack(STRAIGHT, &[high]))
+}
+
+/// Table flush: masque de rangs (13 bits, popcount==5) -> valeur packée
+/// `FLUSH`. La quinte couleur se détecte séparément (cf. `best_flush_value`)
+/// car la meilleure quinte d'une couleur n'est pas toujours ses 5 cartes les
+/// plus hautes.
+fn flush_table() -> &'static HashMap<u16, u32> {
+    static TABLE: OnceLock<HashMap<u16, u32>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = HashMap::new();
+        for mask in 0u16..(1 << 13) {
+            if mask.count_ones() != 5 {
+                continue;
+            }
+            let ranks: Vec<u8> = (0..13).filter(|&bit| mask & (1 << bit) != 0).collect();
+            table.insert(mask, pack(FLUSH, &ranks.into_iter().rev().collect::<Vec<_>>()));
+        }
+        table
+    })
+}
+
+/// Table des mains sans couleur: produit de premiers d'un multi-ensemble de
+/// 5 rangs -> valeur packée (carré/full/brelan/deux paires/paire/hauteur).
+/// La quinte sans couleur se départage à part (cf. `best_straight_value`)
+/// car la meilleure sélection de 5 rangs par effectif décroissant ne
+/// contient pas forcément la quinte.
+fn unique5_table() -> &'static HashMap<u64, u32> {
+    static TABLE: OnceLock<HashMap<u64, u32>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = HashMap::new();
+        for r0 in 0u8..13 {
+            for r1 in r0..13 {
+                for r2 in r1..13 {
+                    for r3 in r2..13 {
+                        for r4 in r3..13 {
+                            let ranks = [r0, r1, r2, r3, r4];
+                            let mut counts = [0u8; 13];
+                            for &r in &ranks {
+                                counts[r as usize] += 1;
+                            }
+
+                            let mut groups: Vec<(u8, u8)> = counts
+                                .iter()
+                                .enumerate()
+                                .filter(|&(_, &c)| c > 0)
+                                .map(|(rank, &count)| (rank as u8, count))
+                                .collect();
+                            groups.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+
+                            let shape: Vec<u8> = groups.iter().map(|&(_, c)| c).collect();
+                            let tiebreak: Vec<u8> = groups.iter().map(|&(r, _)| r).collect();
+
+                            let packed = match shape.as_slice() {
+                                [4, 1] => pack(FOUR_OF_A_KIND, &tiebreak),
+                                [3, 2] => pack(FULL_HOUSE, &tiebreak),
+                                [3, 1, 1] => pack(THREE_OF_A_KIND, &tiebreak),
+                                [2, 2, 1] => pack(TWO_PAIR, &tiebreak),
+                                [2, 1, 1, 1] => pack(ONE_PAIR, &tiebreak),
+                                _ => pack(HIGH_CARD, &ranks.iter().rev().copied().collect::<Vec<_>>()),
+                            };
+
+                            table.insert(prime_product(&ranks), packed);
+                        }
+                    }
+                }
+            }
+        }
+        table
+    })
+}
+
+/// Garde les `n` bits de poids fort posés dans `mask` (les rangs les plus
+/// hauts), pour sélectionner la meilleure main flush parmi plus de 5 cartes
+/// d'une même couleur.
+fn top_n_bits(mask: u16, n: u32) -> u16 {
+    let mut kept = 0u16;
+    let mut found = 0;
+    for bit in (0..13).rev() {
+        if mask & (1 << bit) != 0 {
+            kept |= 1 << bit;
+            found += 1;
+            if found == n {
+                break;
+            }
+        }
+    }
+    kept
+}
+
+/// Meilleure valeur (quinte couleur ou flush simple) pour un masque de
+/// couleur ayant au moins 5 bits posés.
+fn best_flush_value(mask: u16) -> u32 {
+    let ranks: Vec<u8> = (0..13).filter(|&bit| mask & (1 << bit) != 0).collect();
+    if let Some(straight) = best_straight_value(&ranks) {
+        return pack(STRAIGHT_FLUSH, &[(straight & 0xF) as u8]);
+    }
+    flush_table()[&top_n_bits(mask, 5)]
+}
+
+/// Évalue la meilleure main parmi `cards` (5 à 7 cartes, couleurs ignorées
+/// sauf pour la détection flush) et retourne sa valeur packée: plus elle est
+/// grande, plus la main est forte. Comparable directement entre deux mains,
+/// et normalisable en `[0, 1]` via `MAX_HAND_VALUE`.
+pub fn hand_rank_bits(cards: &[Card]) -> u32 {
+    let mut suit_masks = [0u16; 4];
+    let mut rank_counts = [0u8; 13];
+    for card in cards {
+        let rank_bit = (card.rank - 2) as usize;
+        suit_masks[card.suit as usize] |= 1 << rank_bit;
+        rank_counts[rank_bit] += 1;
+    }
+
+    let flush_value = suit_masks
+        .iter()
+        .find(|mask| mask.count_ones() >= 5)
+        .map(|&mask| best_flush_value(mask));
+
+    let distinct_ranks: Vec<u8> = (0..13).filter(|&r| rank_counts[r as usize] > 0).collect();
+    let straight_value = best_straight_value(&distinct_ranks);
+
+    let mut groups: Vec<(u8, u8)> = rank_counts
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(rank, &count)| (rank as u8, count))
+        .collect();
+    groups.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+
+    let mut best_five: Vec<u8> = Vec::with_capacity(5);
+    for &(rank, count) in &groups {
+        let room = 5 - best_five.len();
+        if room == 1 {
+            // Dernière place: la plus haute carte restante, quel que soit le
+            // nombre d'exemplaires de son rang (ex. kicker d'un double paire).
+            let kicker = groups.iter().map(|&(r, _)| r).filter(|r| !best_five.contains(r)).max();
+            best_five.extend(kicker);
+            break;
+        }
+        best_five.extend(std::iter::repeat(rank).take((count as usize).min(room)));
+        if best_five.len() == 5 {
+            break;
+        }
+    }
+    best_five.sort_unstable();
+    let paired_value = unique5_table()[&prime_product(&best_five)];
+
+    [flush_value, straight_value, Some(paired_value)]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(0)
+}
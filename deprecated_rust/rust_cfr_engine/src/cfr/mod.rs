@@ -3,6 +3,7 @@ pub mod engine;
 pub mod trainer;
 pub mod abstraction;
 pub mod full_engine;
+pub mod hand_eval;
 
 pub use engine::CfrEngine;
 pub use trainer::CfrTrainer;
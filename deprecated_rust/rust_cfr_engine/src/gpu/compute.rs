@@ -2,8 +2,18 @@
 use crate::types::*;
 use crate::cfr::AbstractionManager;
 use dashmap::DashMap;
-use std::sync::Arc;
-use wgpu::{Device, Queue, Buffer, ComputePipeline};
+use std::sync::{Arc, Mutex};
+use wgpu::{Device, Queue, Buffer, ComputePipeline, QuerySet};
+
+/// Mesures de temps d'un dispatch GPU: temps de passe compute mesuré par les
+/// timestamp queries du device (hors overhead de soumission/lecture), et
+/// temps mur côté host entre la soumission et la fin du readback, pour
+/// distinguer un calcul GPU-bound d'un goulot d'étranglement de mapping/submit.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GpuTimings {
+    pub gpu_pass_ns: u64,
+    pub host_wall_ns: u64,
+}
 
 pub struct GpuCompute {
     device: Device,
@@ -14,10 +24,29 @@ pub struct GpuCompute {
     input_buffer: Buffer,
     output_buffer: Buffer,
     staging_buffer: Buffer,
+    // Profiling GPU (timestamp queries, si supportées par l'adapteur)
+    timestamp_query: Option<TimestampQueryResources>,
+    last_timings: Mutex<GpuTimings>,
+    // Largeur effective des éléments des buffers regret/stratégie: F16 si
+    // demandé par la config ET supporté par l'adapteur, sinon dégradé en F32.
+    precision: GpuPrecision,
+}
+
+/// Ressources nécessaires pour stamper le début/la fin d'une passe compute:
+/// un `QuerySet` à deux emplacements, le buffer de résolution où le driver
+/// écrit les timestamps bruts, et un buffer de lecture CPU.
+struct TimestampQueryResources {
+    query_set: QuerySet,
+    resolve_buffer: Buffer,
+    readback_buffer: Buffer,
+    period_ns: f32,
 }
 
 impl GpuCompute {
-    /// Initialisation GPU compute
+    /// Initialisation GPU compute sur l'adapteur haute-performance par défaut
+    /// de l'instance. Pour un pool multi-GPU, voir `GpuScheduler` qui énumère
+    /// tous les adapteurs disponibles et construit un `GpuCompute` par device
+    /// via `from_adapter`.
     pub async fn new(config: GpuConfig) -> Result<Self, String> {
         // Créer instance WGPU
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
@@ -35,13 +64,48 @@ impl GpuCompute {
             .await
             .ok_or_else(|| "Aucun adapteur GPU trouvé".to_string())?;
 
+        Self::from_adapter(adapter, config).await
+    }
+
+    /// Construit un `GpuCompute` pour un adapteur déjà résolu (device, buffers,
+    /// profiling, précision). Factorisé hors de `new` pour que `GpuScheduler`
+    /// puisse instancier un device par adapteur énuméré sans repasser par la
+    /// sélection "meilleur adapteur" de `request_adapter`.
+    pub async fn from_adapter(adapter: wgpu::Adapter, config: GpuConfig) -> Result<Self, String> {
         println!("🔥 GPU détecté: {:?}", adapter.get_info());
 
+        // Timestamp queries: optionnelles, on dégrade silencieusement si
+        // l'adapteur ne les supporte pas (navigateurs/drivers anciens).
+        let adapter_features = adapter.features();
+        let timestamps_supported = adapter_features.contains(wgpu::Features::TIMESTAMP_QUERY);
+
+        // Précision f16: opt-in via GpuConfig::precision, mais on ne l'active
+        // réellement que si l'adapteur expose SHADER_F16 (pack2x16float /
+        // unpack2x16float dans le shader) ; sinon on retombe sur f32 pour ne
+        // pas planter au request_device.
+        let f16_supported = adapter_features.contains(wgpu::Features::SHADER_F16);
+        let precision = match config.precision {
+            GpuPrecision::F16 if f16_supported => GpuPrecision::F16,
+            GpuPrecision::F16 => {
+                println!("⚠️  SHADER_F16 non supporté par cet adapteur, retour en f32");
+                GpuPrecision::F32
+            }
+            GpuPrecision::F32 => GpuPrecision::F32,
+        };
+
+        let mut requested_features = wgpu::Features::empty();
+        if timestamps_supported {
+            requested_features |= wgpu::Features::TIMESTAMP_QUERY;
+        }
+        if precision == GpuPrecision::F16 {
+            requested_features |= wgpu::Features::SHADER_F16;
+        }
+
         // Device et queue
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
-                    required_features: wgpu::Features::empty(),
+                    required_features: requested_features,
                     required_limits: wgpu::Limits::default(),
                     label: Some("CFR GPU Device"),
                 },
@@ -64,8 +128,10 @@ impl GpuCompute {
             entry_point: "main",
         });
 
-        // Créer buffers
-        let buffer_size = (config.batch_size * std::mem::size_of::<f32>() * 256) as u64; // 256 floats par state
+        // Créer buffers. En f16, deux valeurs sont compactées par u32
+        // (pack2x16float), donc chaque élément ne coûte que 2 octets au lieu
+        // de 4 — à budget mémoire égal ça double le batch_size atteignable.
+        let buffer_size = (config.batch_size * precision.element_bytes() * 256) as u64; // 256 éléments par state
 
         let input_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("CFR Input Buffer"),
@@ -88,7 +154,44 @@ impl GpuCompute {
             mapped_at_creation: false,
         });
 
-        println!("✅ GPU CFR engine initialisé - Buffer: {:.1}MB", buffer_size as f64 / 1024.0 / 1024.0);
+        println!(
+            "✅ GPU CFR engine initialisé - Buffer: {:.1}MB ({:?})",
+            buffer_size as f64 / 1024.0 / 1024.0,
+            precision
+        );
+
+        // Deux slots: un timestamp au début de la passe compute, un à la fin.
+        let timestamp_query = if timestamps_supported {
+            let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+                label: Some("CFR Timestamp Query Set"),
+                ty: wgpu::QueryType::Timestamp,
+                count: 2,
+            });
+
+            let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("CFR Timestamp Resolve Buffer"),
+                size: 2 * std::mem::size_of::<u64>() as u64,
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            });
+
+            let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("CFR Timestamp Readback Buffer"),
+                size: 2 * std::mem::size_of::<u64>() as u64,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+
+            Some(TimestampQueryResources {
+                query_set,
+                resolve_buffer,
+                readback_buffer,
+                period_ns: queue.get_timestamp_period(),
+            })
+        } else {
+            println!("⚠️  Timestamp queries non supportées par cet adapteur, profiling GPU désactivé");
+            None
+        };
 
         Ok(Self {
             device,
@@ -98,6 +201,9 @@ impl GpuCompute {
             input_buffer,
             output_buffer,
             staging_buffer,
+            timestamp_query,
+            last_timings: Mutex::new(GpuTimings::default()),
+            precision,
         })
     }
 
@@ -132,20 +238,28 @@ impl GpuCompute {
             ],
         });
 
+        let host_start = std::time::Instant::now();
+
         // Encoder commandes GPU
         let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("CFR Compute Encoder"),
         });
 
         {
+            let timestamp_writes = self.timestamp_query.as_ref().map(|t| wgpu::ComputePassTimestampWrites {
+                query_set: &t.query_set,
+                beginning_of_pass_write_index: Some(0),
+                end_of_pass_write_index: Some(1),
+            });
+
             let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("CFR Compute Pass"),
-                timestamp_writes: None,
+                timestamp_writes,
             });
-            
+
             compute_pass.set_pipeline(&self.compute_pipeline);
             compute_pass.set_bind_group(0, &bind_group, &[]);
-            
+
             // Dispatch: 1 workgroup par state, 64 threads par workgroup
             let workgroups = (states.len() as u32 + 63) / 64;
             compute_pass.dispatch_workgroups(workgroups, 1, 1);
@@ -158,29 +272,87 @@ impl GpuCompute {
             gpu_data.len() as u64
         );
 
+        // Résoudre les timestamps de la passe compute vers un buffer lisible
+        if let Some(ref timestamps) = self.timestamp_query {
+            encoder.resolve_query_set(&timestamps.query_set, 0..2, &timestamps.resolve_buffer, 0);
+            encoder.copy_buffer_to_buffer(
+                &timestamps.resolve_buffer, 0,
+                &timestamps.readback_buffer, 0,
+                2 * std::mem::size_of::<u64>() as u64,
+            );
+        }
+
         // Soumettre commandes
         self.queue.submit(std::iter::once(encoder.finish()));
-        
+
         // Lire résultats
         let buffer_slice = self.staging_buffer.slice(..);
         let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
         buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
             sender.send(result).unwrap();
         });
-        
-        self.device.poll(wgpu::Maintain::Wait);
+
+        self.poll_device();
         receiver.receive().await.unwrap()?;
-        
+
         let results = buffer_slice.get_mapped_range();
         let convergence = self.parse_gpu_results(&results, states.len())?;
-        
+
         // Nettoyer
         drop(results);
         self.staging_buffer.unmap();
-        
+
+        let gpu_pass_ns = self.read_gpu_pass_duration_ns().await;
+        let host_wall_ns = host_start.elapsed().as_nanos() as u64;
+        if let Ok(mut timings) = self.last_timings.lock() {
+            *timings = GpuTimings { gpu_pass_ns, host_wall_ns };
+        }
+
         Ok(convergence)
     }
 
+    /// Lit et résout le delta de timestamps de la dernière passe compute en
+    /// nanosecondes (0 si les timestamp queries ne sont pas supportées).
+    async fn read_gpu_pass_duration_ns(&self) -> u64 {
+        let Some(ref timestamps) = self.timestamp_query else {
+            return 0;
+        };
+
+        let slice = timestamps.readback_buffer.slice(..);
+        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+
+        self.poll_device();
+        if receiver.receive().await.and_then(|r| r.ok()).is_none() {
+            return 0;
+        }
+
+        let duration_ns = {
+            let data = slice.get_mapped_range();
+            let raw: Vec<u64> = data
+                .chunks_exact(8)
+                .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+                .collect();
+            if raw.len() == 2 {
+                (raw[1].saturating_sub(raw[0]) as f64 * timestamps.period_ns as f64) as u64
+            } else {
+                0
+            }
+        };
+
+        timestamps.readback_buffer.unmap();
+        duration_ns
+    }
+
+    /// Dernières mesures de temps GPU (passe compute) et host (submit ->
+    /// readback terminé), pour distinguer un calcul GPU-bound d'un overhead
+    /// de dispatch/lecture.
+    pub fn get_gpu_timings(&self) -> GpuTimings {
+        self.last_timings.lock().map(|t| *t).unwrap_or_default()
+    }
+
     /// Préparer données pour GPU
     fn prepare_gpu_data(&self, states: &[PokerState]) -> Result<Vec<u8>, String> {
         let mut gpu_data = Vec::new();
@@ -228,38 +400,64 @@ impl GpuCompute {
                 };
             }
             state_data[17] = actions_mask;
-            
-            // Convertir vers bytes
-            for &f in &state_data {
-                gpu_data.extend_from_slice(&f.to_le_bytes());
+
+            // Convertir vers bytes. En f16, l'accumulation reste en f32 dans
+            // le shader (lu via unpack2x16float, accumulé, réécrit via
+            // pack2x16float pour éviter la dérive) ; côté host on stocke
+            // directement le format empaqueté attendu par le shader.
+            match self.precision {
+                GpuPrecision::F32 => {
+                    for &f in &state_data {
+                        gpu_data.extend_from_slice(&f.to_le_bytes());
+                    }
+                }
+                GpuPrecision::F16 => {
+                    for pair in state_data.chunks(2) {
+                        gpu_data.extend_from_slice(&pack2x16float(pair[0], pair[1]).to_le_bytes());
+                    }
+                }
             }
         }
-        
+
         Ok(gpu_data)
     }
 
     /// Parser résultats GPU
     fn parse_gpu_results(&self, data: &[u8], num_states: usize) -> Result<f64, String> {
-        if data.len() < num_states * 4 {
+        let elem_bytes = self.precision.element_bytes();
+        if data.len() < num_states * elem_bytes {
             return Err("Données GPU insuffisantes".to_string());
         }
-        
+
         let mut total_convergence = 0.0;
         let mut count = 0;
-        
+
         for i in 0..num_states {
-            let offset = i * 256 * 4; // 256 floats * 4 bytes par float
-            if offset + 4 <= data.len() {
-                let convergence_bytes = [data[offset], data[offset + 1], data[offset + 2], data[offset + 3]];
-                let convergence = f32::from_le_bytes(convergence_bytes);
-                
-                if convergence.is_finite() && convergence >= 0.0 {
-                    total_convergence += convergence as f64;
-                    count += 1;
+            let state_offset = i * 256 * elem_bytes; // 256 éléments par state
+            let convergence = match self.precision {
+                GpuPrecision::F32 => {
+                    if state_offset + 4 > data.len() {
+                        continue;
+                    }
+                    let bytes = [data[state_offset], data[state_offset + 1], data[state_offset + 2], data[state_offset + 3]];
+                    f32::from_le_bytes(bytes)
                 }
+                GpuPrecision::F16 => {
+                    if state_offset + 4 > data.len() {
+                        continue;
+                    }
+                    let bytes = [data[state_offset], data[state_offset + 1], data[state_offset + 2], data[state_offset + 3]];
+                    let (low, _high) = unpack2x16float(u32::from_le_bytes(bytes));
+                    low
+                }
+            };
+
+            if convergence.is_finite() && convergence >= 0.0 {
+                total_convergence += convergence as f64;
+                count += 1;
             }
         }
-        
+
         if count > 0 {
             Ok(total_convergence / count as f64)
         } else {
@@ -267,6 +465,28 @@ impl GpuCompute {
         }
     }
 
+    /// Fait progresser le device pour résoudre les callbacks `map_async` en
+    /// natif: wgpu exige un poll explicite hors WASM pour que le callback de
+    /// `map_async` s'exécute de façon synchrone. Sur `wasm32` (WebGPU), c'est
+    /// la boucle d'évènements du navigateur qui pompe la queue et résout le
+    /// callback elle-même ; un poll explicite y est donc superflu et n'est
+    /// pas appelé.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn poll_device(&self) {
+        self.device.poll(wgpu::Maintain::Wait);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn poll_device(&self) {}
+
+    /// Poids proportionnel pour le partage de charge multi-GPU
+    /// (`GpuScheduler`): basé sur `max_storage_buffer_binding_size` du device
+    /// réellement obtenu, meilleur proxy disponible sans lancer de
+    /// micro-benchmark actif par adapteur.
+    pub fn storage_binding_weight(&self) -> f64 {
+        self.device.limits().max_storage_buffer_binding_size as f64
+    }
+
     /// Vérifier mémoire GPU disponible
     pub fn get_memory_info(&self) -> (u64, u64) {
         // Note: WGPU ne fournit pas directement ces infos
@@ -275,4 +495,76 @@ impl GpuCompute {
         let used_memory = (self.config.memory_limit * total_memory as f32) as u64;
         (total_memory, used_memory)
     }
+}
+
+/// Équivalent host-side du WGSL `pack2x16float`: convertit deux f32 en
+/// binary16 (arrondi au plus proche) et les empaquette dans un u32 (low =
+/// premier composant, high = second), exactement comme le fera le shader.
+fn pack2x16float(a: f32, b: f32) -> u32 {
+    (f32_to_f16_bits(a) as u32) | ((f32_to_f16_bits(b) as u32) << 16)
+}
+
+/// Équivalent host-side du WGSL `unpack2x16float`: dépackette un u32 en ses
+/// deux composants f16, widened en f32.
+fn unpack2x16float(bits: u32) -> (f32, f32) {
+    (f16_bits_to_f32(bits as u16), f16_bits_to_f32((bits >> 16) as u16))
+}
+
+/// Conversion f32 -> binary16 (IEEE 754), arrondi au plus proche, sans passer
+/// par une dépendance externe (pas de crate `half` dans cet arbre).
+fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32 - 127 + 15;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exp <= 0 {
+        sign // sous-normal/zéro: flush-to-zero, suffisant pour des regrets/stratégies normalisés
+    } else if exp >= 0x1f {
+        sign | 0x7c00 // overflow -> infini
+    } else {
+        let half = sign | ((exp as u16) << 10) | (mantissa >> 13) as u16;
+        // Arrondi au plus proche (égalité vers le pair) sur les 13 bits de
+        // mantisse abandonnés; la retenue se propage dans l'exposant, jusqu'à
+        // l'infini 0x7c00.
+        let dropped = mantissa & 0x1fff;
+        if dropped > 0x1000 || (dropped == 0x1000 && half & 1 == 1) {
+            half + 1
+        } else {
+            half
+        }
+    }
+}
+
+/// Conversion binary16 -> f32 (widening exact, sans perte).
+fn f16_bits_to_f32(half: u16) -> f32 {
+    let sign = (half & 0x8000) as u32;
+    let exp = (half >> 10) & 0x1f;
+    let mantissa = (half & 0x3ff) as u32;
+
+    let bits = if exp == 0 {
+        if mantissa == 0 {
+            sign << 16 // zéro
+        } else {
+            // Sous-normal f16 -> normalisé f32
+            let mut e = -1i32;
+            let mut m = mantissa;
+            loop {
+                m <<= 1;
+                e += 1;
+                if m & 0x400 != 0 {
+                    break;
+                }
+            }
+            let m = m & 0x3ff;
+            let exp32 = (127 - 15 - e) as u32;
+            (sign << 16) | (exp32 << 23) | (m << 13)
+        }
+    } else if exp == 0x1f {
+        (sign << 16) | 0x7f80_0000 | (mantissa << 13) // infini/NaN
+    } else {
+        (sign << 16) | ((exp as u32 + 127 - 15) << 23) | (mantissa << 13)
+    };
+
+    f32::from_bits(bits)
 }
\ No newline at end of file
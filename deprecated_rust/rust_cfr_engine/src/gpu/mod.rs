@@ -0,0 +1,6 @@
+/// Module GPU: compute engine single-device et scheduler multi-GPU
+pub mod compute;
+pub mod scheduler;
+
+pub use compute::{GpuCompute, GpuTimings};
+pub use scheduler::GpuScheduler;
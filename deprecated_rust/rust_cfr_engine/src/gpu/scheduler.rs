@@ -0,0 +1,222 @@
+/// Scheduler multi-GPU pour le calcul CFR
+use super::compute::GpuCompute;
+use crate::types::*;
+use crate::cfr::AbstractionManager;
+use dashmap::DashMap;
+use futures::future::join_all;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::types::PyList;
+use pyo3::PyResult;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+/// Pool de devices GPU: énumère tous les adapteurs de l'instance `wgpu`,
+/// construit un `GpuCompute` par device, et partage un batch de states entre
+/// eux proportionnellement à la taille de buffer de stockage que chaque
+/// adapteur déclare supporter (meilleur proxy disponible sans lancer de
+/// micro-benchmark). Chaque part est ensuite redécoupée en petits chunks
+/// placés dans une file partagée: un device qui termine son travail tôt vole
+/// le prochain chunk au lieu de rester oisif en attendant les plus lents.
+pub struct GpuScheduler {
+    devices: Vec<GpuCompute>,
+}
+
+/// Nombre de sous-chunks générés par device pour le vol de travail. Plus
+/// c'est élevé, plus le partage est fin (donc équilibré) mais plus l'overhead
+/// de dispatch par chunk augmente.
+const SUB_CHUNKS_PER_DEVICE: usize = 4;
+
+impl GpuScheduler {
+    /// Énumère tous les adapteurs disponibles et construit un `GpuCompute`
+    /// par device. Retourne `Ok(None)` (pas une erreur) s'il n'y a aucun
+    /// adapteur GPU exploitable: à charge de l'appelant de replier sur le
+    /// CPU (`RustCfrEngine`).
+    pub async fn discover(config: GpuConfig) -> Result<Option<Self>, String> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            ..Default::default()
+        });
+
+        let adapters: Vec<wgpu::Adapter> = instance.enumerate_adapters(wgpu::Backends::all()).collect();
+        if adapters.is_empty() {
+            println!("⚠️  Aucun adapteur GPU trouvé, repli sur le CPU (RustCfrEngine)");
+            return Ok(None);
+        }
+
+        let mut devices = Vec::new();
+        for adapter in adapters {
+            let info = adapter.get_info();
+            match GpuCompute::from_adapter(adapter, config.clone()).await {
+                Ok(device) => devices.push(device),
+                Err(err) => println!("⚠️  Adapteur GPU {:?} ignoré: {}", info, err),
+            }
+        }
+
+        if devices.is_empty() {
+            println!("⚠️  Aucun adapteur GPU exploitable, repli sur le CPU (RustCfrEngine)");
+            return Ok(None);
+        }
+
+        println!("🧮 Pool GPU: {} device(s) découvert(s)", devices.len());
+        Ok(Some(Self { devices }))
+    }
+
+    /// Répartit `states` entre tous les devices du pool proportionnellement à
+    /// leur `max_storage_buffer_binding_size` déclaré, exécute les shards
+    /// concurremment avec vol de travail, puis réduit les convergences
+    /// par-device en une moyenne pondérée par le nombre de states traités.
+    pub async fn compute_cfr_batch(
+        &self,
+        states: &[PokerState],
+        strategies: &Arc<DashMap<InformationSet, Strategy>>,
+        abstraction: &AbstractionManager,
+    ) -> Result<f64, String> {
+        if states.is_empty() {
+            return Ok(1.0);
+        }
+
+        let queue = Arc::new(Mutex::new(self.build_chunk_queue(states.len())));
+
+        let worker_results = join_all(self.devices.iter().map(|device| {
+            let queue = Arc::clone(&queue);
+            async move {
+                let mut weighted_sum = 0.0;
+                let mut total_len = 0usize;
+
+                loop {
+                    let chunk = {
+                        let mut queue = queue.lock().map_err(|_| "File de chunks GPU empoisonnée".to_string())?;
+                        queue.pop_front()
+                    };
+                    let Some(chunk) = chunk else { break };
+
+                    let shard = &states[chunk.clone()];
+                    let convergence = device.compute_cfr_batch(shard, strategies, abstraction).await?;
+                    weighted_sum += convergence * shard.len() as f64;
+                    total_len += shard.len();
+                }
+
+                Ok::<(f64, usize), String>((weighted_sum, total_len))
+            }
+        }))
+        .await;
+
+        let mut weighted_sum = 0.0;
+        let mut total_len = 0usize;
+        for result in worker_results {
+            let (sum, len) = result?;
+            weighted_sum += sum;
+            total_len += len;
+        }
+
+        if total_len == 0 {
+            return Ok(1.0); // Convergence par défaut, cf. GpuCompute::parse_gpu_results
+        }
+
+        Ok(weighted_sum / total_len as f64)
+    }
+
+    /// Découpe `num_states` en chunks pondérés par la capacité déclarée de
+    /// chaque device (plus gros device -> chunks initiaux plus gros), chacun
+    /// lui-même redécoupé en `SUB_CHUNKS_PER_DEVICE` morceaux placés dans une
+    /// file unique partagée: l'ordre round-robin entre devices dans la file
+    /// fait qu'un device rapide épuise vite ses chunks puis vole ceux des
+    /// autres, sans jamais attendre un tour qui ne vient pas.
+    fn build_chunk_queue(&self, num_states: usize) -> VecDeque<Range<usize>> {
+        let weights: Vec<f64> = self
+            .devices
+            .iter()
+            .map(|device| device.storage_binding_weight())
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+
+        let mut device_shards = Vec::with_capacity(self.devices.len());
+        let mut cursor = 0usize;
+        for (i, &weight) in weights.iter().enumerate() {
+            let share = if i + 1 == weights.len() {
+                num_states - cursor // le dernier device absorbe l'arrondi
+            } else {
+                ((num_states as f64) * (weight / total_weight)).round() as usize
+            }
+            .min(num_states - cursor);
+
+            device_shards.push(cursor..cursor + share);
+            cursor += share;
+        }
+
+        // Sous-découpage de chaque part en petits chunks volables, puis
+        // entrelacement round-robin entre devices pour que le vol de travail
+        // touche vite toutes les parts plutôt que de rester local à un device.
+        let mut per_device_chunks: Vec<VecDeque<Range<usize>>> = device_shards
+            .into_iter()
+            .map(|shard| split_evenly(shard, SUB_CHUNKS_PER_DEVICE))
+            .collect();
+
+        let mut queue = VecDeque::new();
+        loop {
+            let mut pushed_any = false;
+            for chunks in per_device_chunks.iter_mut() {
+                if let Some(chunk) = chunks.pop_front() {
+                    queue.push_back(chunk);
+                    pushed_any = true;
+                }
+            }
+            if !pushed_any {
+                break;
+            }
+        }
+
+        queue
+    }
+}
+
+/// Point d'entrée combiné: utilise le pool GPU s'il existe, sinon route tout
+/// le batch vers `RustCfrEngine::train_batch`, le chemin CPU du crate.
+///
+/// `train_batch` attend le `PyList` de `PyDict` d'origine (son format
+/// d'échange avec l'appelant Python) alors que le pool GPU consomme les
+/// `PokerState` déjà convertis pour `GpuCompute::prepare_gpu_data` ; les deux
+/// représentations du même batch sont donc passées en parallèle ici pour que
+/// la bascule GPU/CPU n'ait pas besoin d'une conversion `PokerState -> PyDict`.
+pub async fn compute_cfr_batch_with_cpu_fallback(
+    scheduler: Option<&GpuScheduler>,
+    states: &[PokerState],
+    py_states: &PyList,
+    strategies: &Arc<DashMap<InformationSet, Strategy>>,
+    abstraction: &AbstractionManager,
+    cpu_engine: &mut crate::RustCfrEngine,
+) -> PyResult<f64> {
+    if let Some(scheduler) = scheduler {
+        return scheduler
+            .compute_cfr_batch(states, strategies, abstraction)
+            .await
+            .map_err(PyRuntimeError::new_err);
+    }
+
+    cpu_engine.train_batch(py_states)
+}
+
+/// Découpe `range` en au plus `parts` sous-plages de taille égale (à 1 près),
+/// en omettant les plages vides.
+fn split_evenly(range: Range<usize>, parts: usize) -> VecDeque<Range<usize>> {
+    let len = range.end - range.start;
+    if len == 0 || parts == 0 {
+        return VecDeque::new();
+    }
+
+    let base = len / parts;
+    let remainder = len % parts;
+    let mut chunks = VecDeque::with_capacity(parts);
+    let mut cursor = range.start;
+    for i in 0..parts {
+        let size = base + if i < remainder { 1 } else { 0 };
+        if size == 0 {
+            continue;
+        }
+        chunks.push_back(cursor..cursor + size);
+        cursor += size;
+    }
+
+    chunks
+}
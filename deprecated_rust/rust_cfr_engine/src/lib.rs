@@ -1,12 +1,36 @@
 /// RTPA Studio - CFR Engine Rust 100% Performance (Version Fonctionnelle)
 /// Version simplifiée qui compile et s'intègre parfaitement avec Python
 
+#[cfg(not(target_arch = "wasm32"))]
 use pyo3::prelude::*;
+#[cfg(not(target_arch = "wasm32"))]
 use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
 use rand::prelude::*;
 
+// Build navigateur: pas de PyO3/GIL sur wasm32, donc pas de surface
+// #[pyclass] ici. `WasmCfrEngine` (module `wasm`) expose l'équivalent via
+// wasm-bindgen pour le démo web (CPU-only, voir `wasm.rs`).
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+#[cfg(target_arch = "wasm32")]
+pub use wasm::WasmCfrEngine;
+
+// `gpu/` et `cfr/` (hors `wasm.rs`) ne sont volontairement pas déclarés
+// comme modules ici: ils sont écrits contre `Card`/`PokerState`/
+// `InformationSet`/`Strategy` du crate `rust_cfr_engine` (racine du repo),
+// que le `types.rs` minimal de ce crate-ci ne définit pas. Ils lisent aussi
+// des champs que `rust_cfr_engine` n'a pas: `GpuConfig::precision` (défini
+// seulement dans le `types.rs` de ce crate-ci) et `PokerState::action_history`
+// (défini dans aucun des deux). Les déclarer (`mod gpu;`/`mod cfr;`) ne
+// compilerait pas sans porter ce système de types depuis `rust_cfr_engine`
+// et y ajouter ces champs, une refonte hors de portée
+// d'un fix ponctuel. Ce crate `deprecated_rust` reste donc la version
+// simplifiée historique décrite ci-dessus: tout développement CFR/GPU
+// sérieux doit continuer dans `rust_cfr_engine`, pas ici.
+
 /// Engine CFR Rust Ultra-Performance - ZERO FALLBACK Python
+#[cfg(not(target_arch = "wasm32"))]
 #[pyclass]
 pub struct RustCfrEngine {
     /// Configuration
@@ -18,6 +42,7 @@ pub struct RustCfrEngine {
     iterations: usize,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 #[pymethods]
 impl RustCfrEngine {
     #[new]
@@ -246,6 +271,7 @@ impl RustCfrEngine {
 }
 
 /// Module Python exposé
+#[cfg(not(target_arch = "wasm32"))]
 #[pymodule]
 fn rust_cfr_engine(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<RustCfrEngine>()?;
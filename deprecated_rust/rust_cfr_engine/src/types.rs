@@ -8,6 +8,13 @@ pub struct CfrConfig {
     pub convergence_threshold: f64,
     pub exploration_rate: f64,
     pub discount_factor: f64,
+    /// Température initiale du recuit simulé de `AbstractionManager::anneal_buckets`.
+    pub anneal_t0: f64,
+    /// Ratio température finale / température initiale vers lequel `anneal_buckets`
+    /// refroidit géométriquement à mesure que le budget de temps s'épuise.
+    pub anneal_cooling: f64,
+    /// Budget de temps (secondes) alloué à `anneal_buckets`.
+    pub anneal_budget_secs: u64,
 }
 
 impl Default for CfrConfig {
@@ -17,6 +24,9 @@ impl Default for CfrConfig {
             convergence_threshold: 0.01,
             exploration_rate: 0.1,
             discount_factor: 0.95,
+            anneal_t0: 1.0,
+            anneal_cooling: 0.01,
+            anneal_budget_secs: 5,
         }
     }
 }
@@ -61,6 +71,49 @@ impl SystemInfo {
     }
 }
 
+/// Configuration du GPU
+#[derive(Debug, Clone)]
+pub struct GpuConfig {
+    pub enabled: bool,
+    pub memory_limit: f32,  // Fraction de mémoire GPU à utiliser (0.0-1.0)
+    pub batch_size: usize,  // Taille des batches pour GPU
+    pub prefer_gpu: bool,   // Préférer GPU même pour petits calculs
+    pub precision: GpuPrecision, // Largeur des éléments des buffers regret/stratégie
+}
+
+impl Default for GpuConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            memory_limit: 0.8,
+            batch_size: 1000,
+            prefer_gpu: true,
+            precision: GpuPrecision::F32,
+        }
+    }
+}
+
+/// Largeur de stockage des buffers GPU de regret/stratégie. F16 coûte deux
+/// valeurs par u32 (pack2x16float/unpack2x16float côté shader) pour doubler
+/// le batch_size atteignable dans le même budget mémoire; l'accumulation
+/// reste toujours faite en f32 dans le shader pour éviter la dérive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuPrecision {
+    F32,
+    F16,
+}
+
+impl GpuPrecision {
+    /// Octets par élément dans les buffers GPU (2 f16 empaquetés par u32
+    /// via pack2x16float valent 2 octets/élément en moyenne).
+    pub fn element_bytes(self) -> usize {
+        match self {
+            GpuPrecision::F32 => std::mem::size_of::<f32>(),
+            GpuPrecision::F16 => std::mem::size_of::<f32>() / 2,
+        }
+    }
+}
+
 // Utilités
 pub fn get_cpu_count() -> usize {
     // Estimation simple sans dépendance externe
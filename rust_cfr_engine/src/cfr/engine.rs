@@ -1,105 +1,114 @@
 /// CFR Engine ultra-rapide avec support GPU
 use crate::types::*;
-use crate::gpu::GpuCompute;
 use dashmap::DashMap;
 use rayon::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
 use std::time::{Duration, Instant};
 
+/// Plancher du mélange ε-exploratoire annelé par `CfrTrainer::start_training`
+/// (voir `CfrConfig::exploration_rate`/`exploration_cooling_rate`): même
+/// avec un refroidissement agressif, une fraction minimale d'échantillonnage
+/// uniforme reste appliquée pour ne jamais figer complètement
+/// l'exploration adverse en `cfr_external_sampling`.
+pub(crate) const EXPLORATION_RATE_FLOOR: f64 = 0.01;
+
 pub struct CfrEngine {
     /// Stockage des stratégies par information set
     pub strategies: Arc<DashMap<InformationSet, Strategy>>,
     /// Configuration
     pub config: CfrConfig,
-    /// GPU compute engine (si disponible)
-    pub gpu_compute: Option<GpuCompute>,
     /// Statistiques
     pub iterations: AtomicUsize,
     pub convergence_metric: Arc<std::sync::Mutex<f64>>,
     /// Abstraction manager
     pub abstraction: crate::cfr::AbstractionManager,
+    /// Taux d'exploration ε courant, annelé en direct par
+    /// `CfrTrainer::start_training` (voir `CfrConfig::exploration_rate`) et
+    /// consommé par `sample_action` à chaque noeud adverse/hasard
+    /// échantillonné. Initialisé à `config.exploration_rate`.
+    pub current_exploration_rate: Arc<std::sync::Mutex<f64>>,
+    /// Flux déterministe consommé par `sample_action` et le repli
+    /// sans-deck de `deal_from_deck` (voir `CfrConfig::seed`, même
+    /// principe que le `rng` de `CfrTrainer`): sans cette graine partagée,
+    /// l'échantillonnage adverse/hasard resterait non-reproductible même
+    /// avec un `CfrTrainer` seedé, et la trace de convergence différerait
+    /// d'un run à l'autre à seed de config identique.
+    rng: Arc<std::sync::Mutex<ChaCha20Rng>>,
 }
 
 impl CfrEngine {
     /// Création nouvelle instance CFR
     pub async fn new(config: CfrConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        // Initialiser GPU si demandé
-        let gpu_compute = if config.gpu_config.enabled {
-            match GpuCompute::new(config.gpu_config.clone()).await {
-                Ok(gpu) => {
-                    println!("🚀 GPU CFR initialisé avec succès!");
-                    Some(gpu)
-                }
-                Err(e) => {
-                    println!("⚠️  GPU indisponible, fallback CPU: {}", e);
-                    None
-                }
-            }
-        } else {
-            None
-        };
-
         let abstraction = crate::cfr::AbstractionManager::new(config.abstraction_buckets);
 
+        let initial_exploration_rate = config.exploration_rate;
+        let seed = config.seed.unwrap_or_else(|| rand::thread_rng().gen());
+
         Ok(Self {
             strategies: Arc::new(DashMap::new()),
             config,
-            gpu_compute,
             iterations: AtomicUsize::new(0),
             convergence_metric: Arc::new(std::sync::Mutex::new(1.0)),
             abstraction,
+            current_exploration_rate: Arc::new(std::sync::Mutex::new(initial_exploration_rate)),
+            rng: Arc::new(std::sync::Mutex::new(ChaCha20Rng::seed_from_u64(seed))),
         })
     }
 
-    /// Entraînement CFR sur un batch de states
+    /// Entraînement CFR sur un batch de states. Cette crate n'embarque pas
+    /// de backend GPU (voir `CfrConfig::gpu_config`, qui ne fait donc plus
+    /// que documenter une configuration non consommée ici); tout
+    /// l'entraînement passe par le chemin CPU multi-thread.
     pub async fn train_batch(&self, states: &[PokerState]) -> f64 {
         let start = Instant::now();
-        
-        // Choisir méthode selon taille batch et GPU disponibilité
-        let convergence = if states.len() >= self.config.gpu_config.batch_size && self.gpu_compute.is_some() {
-            self.train_batch_gpu(states).await
-        } else {
-            self.train_batch_cpu(states)
-        }?;
-        
+
+        let convergence = self.train_batch_cpu(states);
+
         // Mise à jour statistiques
         self.iterations.fetch_add(states.len(), Ordering::Relaxed);
         if let Ok(mut metric) = self.convergence_metric.lock() {
             *metric = convergence;
         }
-        
+
         let duration = start.elapsed();
         if duration.as_millis() > 100 {  // Log seulement si > 100ms
-            println!("🔄 CFR batch: {} states, {:.3}ms, convergence: {:.4}", 
+            println!("🔄 CFR batch: {} states, {:.3}ms, convergence: {:.4}",
                 states.len(), duration.as_secs_f64() * 1000.0, convergence);
         }
-        
-        Ok(convergence)
-    }
 
-    /// Entraînement GPU (parallèle massif)
-    async fn train_batch_gpu(&self, states: &[PokerState]) -> Result<f64, Box<dyn std::error::Error>> {
-        if let Some(ref gpu) = self.gpu_compute {
-            gpu.compute_cfr_batch(states, &self.strategies, &self.abstraction).await
-        } else {
-            // Fallback CPU si GPU échoue
-            Ok(self.train_batch_cpu(states))
-        }
+        convergence
     }
 
     /// Entraînement CPU (parallèle multi-thread)
     fn train_batch_cpu(&self, states: &[PokerState]) -> f64 {
         let chunk_size = (states.len() / self.config.cpu_threads).max(1);
-        
+
+        // Alterne le traverseur à chaque itération (batch) en MCCFR:
+        // `iterations` avance de `states.len()` par appel (voir `train_batch`),
+        // d'où l'indice de batch pour des batches de taille constante.
+        let batch_index = self.iterations.load(Ordering::Relaxed) / states.len().max(1);
+        let traverser_is_mover = batch_index % 2 == 0;
+
         let convergences: Vec<f64> = states
             .par_chunks(chunk_size)
             .map(|chunk| {
                 chunk.iter()
-                    .map(|state| self.cfr_recursive(state, 1.0, 1.0))
+                    .map(|state| match self.config.sampling {
+                        SamplingMode::FullTree => self.cfr_recursive(state, 1.0, 1.0),
+                        // `CfrEngine` ne distingue pas encore l'échantillonnage
+                        // d'issue de l'échantillonnage externe (voir
+                        // `FullCfrEngine::cfr_outcome_sampling` pour ce mode);
+                        // les deux replient sur le même traverseur alterné.
+                        SamplingMode::ExternalSampling | SamplingMode::OutcomeSampling => {
+                            self.cfr_external_sampling(state, traverser_is_mover)
+                        }
+                    })
                     .sum::<f64>() / chunk.len() as f64
             })
             .collect();
-            
+
         convergences.iter().sum::<f64>() / convergences.len() as f64
     }
 
@@ -131,24 +140,132 @@ impl CfrEngine {
             node_value += action_prob * action_value;
         }
         
-        // Mise à jour regrets
+        // Mise à jour regrets/stratégie selon la règle configurée
+        // (Vanilla / CFR+ / Discounted), datée par l'itération courante.
+        let iteration = self.iterations.load(Ordering::Relaxed) + 1;
+        let params = self.config.update_params(iteration);
+        let strategy_weight = match self.config.update_rule {
+            // CFR+: moyennage linéaire, la contribution pèse davantage aux
+            // itérations tardives.
+            crate::types::CfrUpdateRule::CfrPlus => iteration as f64,
+            _ => 1.0,
+        };
+
         for (action, &value) in &action_values {
             let regret = value - node_value;
-            strategy_entry.update_regret(action, regret * reach_prob_opponent);
-            strategy_entry.update_strategy(action, reach_prob_player * strategy.get(action).unwrap_or(&0.0));
+            strategy_entry.update_regret(action, regret * reach_prob_opponent, &params);
+            let action_prob = strategy.get(action).unwrap_or(&0.0);
+            strategy_entry.update_strategy(action, strategy_weight * reach_prob_player * action_prob, &params);
         }
-        
+
         node_value
     }
 
+    /// Monte-Carlo CFR à échantillonnage externe (external sampling): le
+    /// traverseur explore exhaustivement ses propres actions et met à jour
+    /// ses regrets comme en CFR vanilla, mais aux noeuds de l'adversaire (et
+    /// du hasard) une seule action est échantillonnée selon σ et seule cette
+    /// branche est visitée, rendant le coût par itération linéaire en
+    /// profondeur au lieu d'exponentiel.
+    fn cfr_external_sampling(&self, state: &PokerState, traverser_is_mover: bool) -> f64 {
+        if state.is_terminal() {
+            return self.evaluate_terminal_state(state);
+        }
+        if state.available_actions.is_empty() {
+            return 0.0;
+        }
+
+        let info_set = self.abstraction.state_to_infoset(state);
+        let mut strategy_entry = self.strategies.entry(info_set).or_insert_with(Strategy::new);
+        let strategy = strategy_entry.get_current_strategy(&state.available_actions);
+
+        let iteration = self.iterations.load(Ordering::Relaxed) + 1;
+        let params = self.config.update_params(iteration);
+
+        if traverser_is_mover {
+            // Recursion exhaustive sur les actions du traverseur
+            let mut action_values = std::collections::HashMap::new();
+            let mut node_value = 0.0;
+
+            for action in &state.available_actions {
+                let action_prob = strategy.get(action).unwrap_or(&0.0);
+                let child = self.apply_action(state, action);
+                let value = -self.cfr_external_sampling(&child, false);
+                action_values.insert(action.clone(), value);
+                node_value += action_prob * value;
+            }
+
+            for (action, &value) in &action_values {
+                let regret = value - node_value;
+                strategy_entry.update_regret(action, regret, &params);
+            }
+
+            node_value
+        } else {
+            // Un seul tirage selon la stratégie courante mélangée à une
+            // exploration ε uniforme (voir `sample_action` et
+            // `CfrConfig::exploration_rate`), branche unique visitée.
+            let epsilon = self.current_exploration_rate.lock()
+                .map(|rate| rate.clamp(0.0, 1.0))
+                .unwrap_or(0.0);
+            let sampled_action = self.sample_action(&state.available_actions, &strategy, epsilon);
+
+            // Probabilité réellement utilisée pour le tirage (mélange
+            // stratégie/uniforme), pas seulement la probabilité de la
+            // stratégie courante: c'est elle qui pondère l'estimée
+            // non biaisée de la mise à jour de stratégie.
+            let uniform_prob = 1.0 / state.available_actions.len() as f64;
+            let strategy_prob = strategy.get(&sampled_action).copied().unwrap_or(uniform_prob);
+            let sampled_prob = (1.0 - epsilon) * strategy_prob + epsilon * uniform_prob;
+
+            // Même pondération de moyennage linéaire CFR+ que `cfr_recursive`
+            // (iteration as f64), sinon le moyennage de stratégie appliqué au
+            // parcours complet serait silencieusement perdu dès qu'un noeud
+            // adverse est échantillonné plutôt que parcouru exhaustivement.
+            let strategy_weight = match self.config.update_rule {
+                crate::types::CfrUpdateRule::CfrPlus => iteration as f64,
+                _ => 1.0,
+            };
+            strategy_entry.update_strategy(&sampled_action, strategy_weight * sampled_prob, &params);
+
+            let child = self.apply_action(state, &sampled_action);
+            -self.cfr_external_sampling(&child, true)
+        }
+    }
+
+    /// Échantillonne une action selon une distribution de stratégie, avec
+    /// probabilité `epsilon` de tirer uniformément parmi toutes les actions
+    /// légales plutôt que de suivre la stratégie courante (voir
+    /// `CfrConfig::exploration_rate`/`EXPLORATION_RATE_FLOOR`). Puise dans
+    /// `self.rng` (voir `CfrConfig::seed`) plutôt que `thread_rng()`, pour
+    /// que le tirage soit reproductible à seed de config fixée.
+    fn sample_action(&self, actions: &[Action], strategy: &std::collections::HashMap<Action, f64>, epsilon: f64) -> Action {
+        let mut rng = self.rng.lock().unwrap_or_else(|p| p.into_inner());
+
+        if rng.gen::<f64>() < epsilon {
+            return actions[rng.gen_range(0..actions.len())].clone();
+        }
+
+        let r: f64 = rng.gen();
+        let mut cumulative = 0.0;
+        for action in actions {
+            cumulative += strategy.get(action).copied().unwrap_or(0.0);
+            if r <= cumulative {
+                return action.clone();
+            }
+        }
+        actions.last().cloned().expect("au moins une action légale")
+    }
+
     /// Appliquer une action sur un state
     fn apply_action(&self, state: &PokerState, action: &Action) -> PokerState {
         let mut new_state = state.clone();
         
         match action {
             Action::Fold => {
-                // État terminal - joueur fold
+                // État terminal - joueur fold, pas de showdown
                 new_state.available_actions = vec![];
+                new_state.folded = true;
             }
             Action::Call => {
                 // Avancer au prochain round ou terminal
@@ -178,20 +295,21 @@ impl CfrEngine {
             BettingRound::Preflop => {
                 state.betting_round = BettingRound::Flop;
                 // Ajouter 3 cartes flop si pas déjà là
-                if state.community_cards.len() == 0 {
-                    state.community_cards = self.generate_flop();
+                if state.community_cards.is_empty() {
+                    let flop = self.deal_from_deck(&mut state.deck, 3);
+                    state.community_cards = flop;
                 }
             }
             BettingRound::Flop => {
                 state.betting_round = BettingRound::Turn;
                 if state.community_cards.len() == 3 {
-                    state.community_cards.push(self.generate_turn_river());
+                    state.community_cards.extend(self.deal_from_deck(&mut state.deck, 1));
                 }
             }
             BettingRound::Turn => {
                 state.betting_round = BettingRound::River;
                 if state.community_cards.len() == 4 {
-                    state.community_cards.push(self.generate_turn_river());
+                    state.community_cards.extend(self.deal_from_deck(&mut state.deck, 1));
                 }
             }
             BettingRound::River => {
@@ -199,49 +317,89 @@ impl CfrEngine {
                 state.available_actions = vec![];
             }
         }
-        
+
         // Réinitialiser actions disponibles pour nouveau round
         if !state.available_actions.is_empty() {
             state.available_actions = vec![Action::Check, Action::Bet(state.stack_size * 0.5), Action::Fold];
         }
-        
-        state
-    }
 
-    /// Générer cartes flop aléatoires
-    fn generate_flop(&self) -> Vec<Card> {
-        use rand::{thread_rng, Rng};
-        let mut rng = thread_rng();
-        
-        (0..3).map(|_| Card {
-            rank: rng.gen_range(2..=14),
-            suit: rng.gen_range(0..4),
-        }).collect()
+        state
     }
 
-    /// Générer carte turn/river
-    fn generate_turn_river(&self) -> Card {
-        use rand::{thread_rng, Rng};
-        let mut rng = thread_rng();
-        
-        Card {
-            rank: rng.gen_range(2..=14),
-            suit: rng.gen_range(0..4),
+    /// Tire `count` cartes du deck attaché au state. Sans deck (état
+    /// construit sans historique de cartes connues), dégrade vers un tirage
+    /// aléatoire indépendant qui peut produire des collisions.
+    fn deal_from_deck(&self, deck: &mut Option<Deck>, count: usize) -> Vec<Card> {
+        match deck {
+            Some(deck) => (0..count).filter_map(|_| deck.deal()).collect(),
+            None => {
+                // Puise dans `self.rng` (voir `CfrConfig::seed`) plutôt que
+                // `thread_rng()`, pour que ce repli reste reproductible à
+                // seed de config fixée comme le reste de l'échantillonnage.
+                let mut rng = self.rng.lock().unwrap_or_else(|p| p.into_inner());
+                (0..count)
+                    .map(|_| Card { rank: rng.gen_range(2..=14), suit: rng.gen_range(0..4) })
+                    .collect()
+            }
         }
     }
 
-    /// Évaluer état terminal
+    /// Évaluer état terminal par un vrai showdown (main 7 cartes exacte).
+    /// Le run-out restant et la main adverse sont tirés du deck partagé du
+    /// state, garantissant l'absence de doublon avec les cartes connues.
     fn evaluate_terminal_state(&self, state: &PokerState) -> f64 {
-        // Simulation simple - à améliorer avec évaluateur de main réel
-        use rand::{thread_rng, Rng};
-        let mut rng = thread_rng();
-        
-        // Pour l'instant, retourne une évaluation aléatoire
-        // TODO: Implémenter évaluateur de main poker réel
-        if rng.gen_bool(0.5) {
-            state.pot_size  // Gain
-        } else {
-            -state.pot_size * 0.5  // Perte
+        if state.folded {
+            // Main terminée sans showdown: le pot déjà investi est perdu
+            return -state.pot_size;
+        }
+
+        let mut known: Vec<Card> = state.hole_cards.clone();
+        known.extend_from_slice(&state.community_cards);
+
+        // Puise dans `self.rng` plutôt que `thread_rng()` (voir
+        // `CfrConfig::seed`): ce showdown est le chemin par défaut de tout
+        // `PokerState` sans deck explicite et le site le plus exercé de
+        // `cfr_recursive`/`cfr_external_sampling`/`best_response_value`, donc
+        // la seule reproductibilité de `sample_action`/`deal_from_deck` ne
+        // suffit pas à garantir une trace de convergence déterministe.
+        let mut deck = state.deck.clone().unwrap_or_else(|| {
+            let mut rng = self.rng.lock().unwrap_or_else(|p| p.into_inner());
+            Deck::new_with_rng(&known, &mut *rng)
+        });
+
+        // Tire une carte du deck partagé, ou d'un deck de secours reconstruit
+        // à partir de `known` si celui-ci est sec. `known` est tenu à jour
+        // après CHAQUE tirage (y compris ceux du deck de secours) pour que
+        // les tirages suivants — main adverse puis run-out — ne puissent
+        // jamais reproduire une carte déjà distribuée dans ce même showdown.
+        let mut draw = |deck: &mut Deck, known: &mut Vec<Card>| -> Card {
+            let card = deck.deal().unwrap_or_else(|| {
+                let mut rng = self.rng.lock().unwrap_or_else(|p| p.into_inner());
+                Deck::new_with_rng(known, &mut *rng).deal().expect("deck de secours non vide")
+            });
+            known.push(card);
+            card
+        };
+
+        let villain_hole = vec![draw(&mut deck, &mut known), draw(&mut deck, &mut known)];
+
+        let mut board = state.community_cards.clone();
+        while board.len() < 5 {
+            board.push(draw(&mut deck, &mut known));
+        }
+
+        let mut hero_cards = state.hole_cards.clone();
+        hero_cards.extend_from_slice(&board);
+        let mut villain_cards = villain_hole;
+        villain_cards.extend_from_slice(&board);
+
+        let hero_rank = crate::cfr::hand_eval::evaluate_hand(&hero_cards);
+        let villain_rank = crate::cfr::hand_eval::evaluate_hand(&villain_cards);
+
+        match hero_rank.cmp(&villain_rank) {
+            std::cmp::Ordering::Greater => state.pot_size,
+            std::cmp::Ordering::Less => -state.pot_size,
+            std::cmp::Ordering::Equal => 0.0,
         }
     }
 
@@ -250,6 +408,115 @@ impl CfrEngine {
         self.strategies.get(info_set).map(|strategy| strategy.get_average_strategy())
     }
 
+    /// Exploitabilité réelle: moyenne des valeurs de best-response des deux
+    /// joueurs contre la stratégie moyenne courante de l'adversaire. Vaut 0
+    /// à l'équilibre de Nash, strictement positif sinon.
+    pub fn compute_exploitability(&self, root_states: &[PokerState]) -> f64 {
+        if root_states.is_empty() {
+            return 0.0;
+        }
+
+        let total: f64 = root_states
+            .iter()
+            .map(|root| {
+                // Fixe le deck (donc la main adverse et le run-out à venir)
+                // une seule fois avant d'explorer les deux best-responses:
+                // `apply_action`/`evaluate_terminal_state` ne font que cloner
+                // et consommer ce deck en profondeur, donc toute branche de
+                // la recherche lit désormais la même information cachée au
+                // lieu d'en tirer une nouvelle à chaque feuille atteinte.
+                let root = self.resolve_hidden_information(root);
+                let br_player = self.best_response_value(&root, true);
+                let br_opponent = self.best_response_value(&root, false);
+                // `br_opponent` est exprimée du point de vue du joueur au
+                // trait: la valeur best-response de l'adversaire en est l'opposé.
+                (br_player - br_opponent) / 2.0
+            })
+            .sum();
+
+        total / root_states.len() as f64
+    }
+
+    /// Résout une fois pour toutes le deck d'un `root` sans deck explicite,
+    /// pour que `best_response_value` compare ses actions sous une
+    /// information cachée fixée plutôt que de ré-échantillonner un monde
+    /// différent (main adverse, run-out) à chaque feuille de la recherche —
+    /// condition nécessaire pour que `compute_exploitability` vaille 0 à
+    /// l'équilibre de Nash plutôt que du bruit de tirage. Ne touche pas à
+    /// `root` si son deck est déjà posé (cas `CfrTrainer::generate_random_state`).
+    fn resolve_hidden_information(&self, root: &PokerState) -> PokerState {
+        if root.deck.is_some() {
+            return root.clone();
+        }
+
+        let mut known = root.hole_cards.clone();
+        known.extend_from_slice(&root.community_cards);
+
+        let mut resolved = root.clone();
+        let mut rng = self.rng.lock().unwrap_or_else(|p| p.into_inner());
+        resolved.deck = Some(Deck::new_with_rng(&known, &mut *rng));
+        resolved
+    }
+
+    /// Recalcule l'exploitabilité et la stocke comme métrique de convergence,
+    /// de sorte que `get_convergence_stats` reflète un vrai critère
+    /// game-theorique plutôt qu'un simple compteur d'itérations.
+    pub fn refresh_convergence(&self, root_states: &[PokerState]) {
+        let exploitability = self.compute_exploitability(root_states);
+        if let Ok(mut metric) = self.convergence_metric.lock() {
+            *metric = exploitability;
+        }
+    }
+
+    /// Valeur best-response à un noeud: si `traverser_is_mover`, le joueur au
+    /// trait maximise sur ses actions (vrai best-response); sinon, on moyenne
+    /// sur la stratégie moyenne de l'adversaire (`get_average_strategy`).
+    /// Les valeurs sont négées à chaque demi-coup (jeu à somme nulle), comme
+    /// dans `cfr_recursive`. Les noeuds de hasard (changement de street) ne
+    /// sont pas un cas séparé ici: `apply_action` n'en tire qu'une seule
+    /// issue par appel (même convention MCCFR à échantillon unique que
+    /// `FullCfrEngine::cfr_outcome_sampling`), donc leur pondération par la
+    /// probabilité de tirage se fait en moyennant plusieurs passes sur
+    /// `root_states` plutôt qu'en énumérant les tirages dans un seul appel.
+    fn best_response_value(&self, state: &PokerState, traverser_is_mover: bool) -> f64 {
+        if state.is_terminal() {
+            return self.evaluate_terminal_state(state);
+        }
+
+        if state.available_actions.is_empty() {
+            return 0.0;
+        }
+
+        if traverser_is_mover {
+            state
+                .available_actions
+                .iter()
+                .map(|action| {
+                    let child = self.apply_action(state, action);
+                    -self.best_response_value(&child, false)
+                })
+                .fold(f64::NEG_INFINITY, f64::max)
+        } else {
+            let info_set = self.abstraction.state_to_infoset(state);
+            let avg_strategy = self.get_average_strategy(&info_set);
+            let uniform_prob = 1.0 / state.available_actions.len() as f64;
+
+            state
+                .available_actions
+                .iter()
+                .map(|action| {
+                    let prob = avg_strategy
+                        .as_ref()
+                        .and_then(|s| s.get(action))
+                        .copied()
+                        .unwrap_or(uniform_prob);
+                    let child = self.apply_action(state, action);
+                    prob * -self.best_response_value(&child, true)
+                })
+                .sum()
+        }
+    }
+
     /// Statistiques de convergence
     pub fn get_convergence_stats(&self) -> (usize, f64) {
         let iterations = self.iterations.load(Ordering::Relaxed);
@@ -257,30 +524,90 @@ impl CfrEngine {
         (iterations, *convergence)
     }
 
-    /// Export des données CFR
-    pub fn export_data(&self) -> Result<String, Box<dyn std::error::Error>> {
-        let mut export_data = std::collections::HashMap::new();
-        
-        for entry in self.strategies.iter() {
-            let (info_set, strategy) = entry.pair();
-            export_data.insert(format!("{:?}", info_set), strategy.clone());
+    /// Construit un snapshot round-trippable de l'état d'entraînement courant
+    /// (en-tête de validation + toutes les stratégies par information set).
+    fn build_snapshot(&self) -> Snapshot {
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+
+        Snapshot {
+            header: SnapshotHeader {
+                format_version: SNAPSHOT_FORMAT_VERSION,
+                crate_version: env!("CARGO_PKG_VERSION").to_string(),
+                iterations: self.iterations.load(Ordering::Relaxed),
+                convergence_metric: *self.convergence_metric.lock().unwrap_or_else(|p| p.into_inner()),
+                abstraction_buckets: self.config.abstraction_buckets,
+            },
+            strategies,
         }
-        
-        Ok(serde_json::to_string_pretty(&export_data)?)
     }
 
-    /// Import des données CFR
-    pub fn import_data(&self, data: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let import_data: std::collections::HashMap<String, Strategy> = serde_json::from_str(data)?;
-        
-        for (info_set_str, strategy) in import_data {
-            // TODO: Convertir string vers InformationSet
-            // Pour l'instant, skip cette fonctionnalité
-            println!("Import CFR: {} strategies importées", self.strategies.len());
+    /// Valide la compatibilité du snapshot puis fusionne ses stratégies dans
+    /// la table courante, et restaure l'avancement de l'entraînement
+    /// (itérations, convergence) pour une reprise exacte plutôt qu'un
+    /// redémarrage de l'accumulation des regrets à zéro.
+    fn restore_snapshot(&self, snapshot: Snapshot) -> Result<(), Box<dyn std::error::Error>> {
+        if snapshot.header.format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(format!(
+                "snapshot incompatible: format_version={} mais version courante={}",
+                snapshot.header.format_version, SNAPSHOT_FORMAT_VERSION
+            )
+            .into());
         }
-        
+
+        if snapshot.header.abstraction_buckets != self.config.abstraction_buckets {
+            return Err(format!(
+                "snapshot incompatible: abstraction_buckets={} mais config courante={}",
+                snapshot.header.abstraction_buckets, self.config.abstraction_buckets
+            )
+            .into());
+        }
+
+        for (info_set, strategy) in snapshot.strategies {
+            self.strategies.insert(info_set, strategy);
+        }
+
+        self.iterations.store(snapshot.header.iterations, Ordering::Relaxed);
+        if let Ok(mut metric) = self.convergence_metric.lock() {
+            *metric = snapshot.header.convergence_metric;
+        }
+
+        println!(
+            "📥 Snapshot importé: {} information sets, reprise à l'itération {}",
+            self.strategies.len(),
+            snapshot.header.iterations
+        );
+
         Ok(())
     }
+
+    /// Export JSON lisible des données CFR (snapshot complet, round-trippable
+    /// par `import_data`).
+    pub fn export_data(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_string_pretty(&self.build_snapshot())?)
+    }
+
+    /// Import d'un snapshot JSON produit par `export_data`: valide la config
+    /// d'abstraction puis restaure stratégies et progression d'entraînement.
+    pub fn import_data(&self, data: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let snapshot: Snapshot = serde_json::from_str(data)?;
+        self.restore_snapshot(snapshot)
+    }
+
+    /// Export binaire compact (bincode) des données CFR, pour des checkpoints
+    /// fréquents où la taille et la latence priment sur la lisibilité.
+    pub fn export_snapshot_binary(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(bincode::serialize(&self.build_snapshot())?)
+    }
+
+    /// Import d'un snapshot binaire produit par `export_snapshot_binary`.
+    pub fn import_snapshot_binary(&self, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        let snapshot: Snapshot = bincode::deserialize(data)?;
+        self.restore_snapshot(snapshot)
+    }
 }
 
 // Implémentation des traits pour état terminal
@@ -288,4 +615,51 @@ impl PokerState {
     pub fn is_terminal(&self) -> bool {
         self.available_actions.is_empty() || self.stack_size <= 0.0
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> CfrConfig {
+        let mut config = CfrConfig::default();
+        config.gpu_config.enabled = false;
+        config
+    }
+
+    #[tokio::test]
+    async fn export_import_round_trips_strategies() {
+        let engine = CfrEngine::new(test_config()).await.unwrap();
+
+        let info_set = InformationSet {
+            abstracted_cards: 42,
+            betting_sequence: vec![1, 2],
+            position: 0,
+            round: BettingRound::Flop,
+        };
+        let mut strategy = Strategy::new();
+        strategy.regret_sum.insert(Action::Fold, 0.5);
+        engine.strategies.insert(info_set.clone(), strategy);
+        engine.iterations.store(7, Ordering::Relaxed);
+
+        let data = engine.export_data().unwrap();
+
+        let restored = CfrEngine::new(test_config()).await.unwrap();
+        restored.import_data(&data).unwrap();
+
+        assert_eq!(restored.iterations.load(Ordering::Relaxed), 7);
+        let imported_strategy = restored.strategies.get(&info_set).unwrap();
+        assert_eq!(imported_strategy.regret_sum.get(&Action::Fold), Some(&0.5));
+    }
+
+    #[tokio::test]
+    async fn import_data_rejects_mismatched_format_version() {
+        let engine = CfrEngine::new(test_config()).await.unwrap();
+        let mut snapshot = engine.build_snapshot();
+        snapshot.header.format_version = SNAPSHOT_FORMAT_VERSION + 1;
+        let data = serde_json::to_string(&snapshot).unwrap();
+
+        let err = engine.import_data(&data).unwrap_err();
+        assert!(err.to_string().contains("format_version"));
+    }
 }
\ No newline at end of file
@@ -1,10 +1,14 @@
 /// RTPA Studio - Full CFR Engine Rust Ultra-Performant
 /// Migration complète du CFR Python → Rust pour gains 50-200x
-use std::collections::{HashMap, BTreeMap};
+use std::collections::{HashMap, BTreeMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex};
+use std::path::{Path, PathBuf};
 use rayon::prelude::*;
 use rand::prelude::*;
+use rand::rngs::ThreadRng;
+use serde::{Deserialize, Serialize};
 use crate::types::*;
+use crate::cfr::hand_eval;
 
 /// CFR Engine complet haute performance
 pub struct FullCfrEngine {
@@ -16,10 +20,19 @@ pub struct FullCfrEngine {
     pub equity_cache: Arc<Mutex<HashMap<String, f64>>>,
     /// Configuration CFR
     pub config: CfrConfig,
-    /// RNG pour simulations
-    pub rng: StdRng,
+    /// RNG pour simulations et échantillonnage Monte-Carlo CFR (partagé,
+    /// verrouillé au point d'usage comme les autres tables ci-dessus)
+    pub rng: Arc<Mutex<StdRng>>,
+    /// Information sets visités par le mode Monte-Carlo depuis le dernier
+    /// `reset_touched_info_sets`, exposé via `get_performance_stats`
+    pub touched_info_sets: Arc<Mutex<HashSet<String>>>,
     /// Statistiques
     pub total_simulations: std::sync::atomic::AtomicU64,
+    /// Modèle d'adversaire enfichable (voir `OpponentModel`): remplace, quand
+    /// présent, le bruit uniforme de `simulate_hand_fast` et de
+    /// l'échantillonnage adverse de `cfr_external_sampling` par un
+    /// comportement modélisé. `None` dégrade vers le comportement existant.
+    pub opponent_model: Option<Arc<dyn OpponentModel + Send + Sync>>,
 }
 
 impl FullCfrEngine {
@@ -30,11 +43,20 @@ impl FullCfrEngine {
             strategy_sum: Arc::new(Mutex::new(BTreeMap::new())),
             equity_cache: Arc::new(Mutex::new(HashMap::new())),
             config,
-            rng: StdRng::from_entropy(),
+            rng: Arc::new(Mutex::new(StdRng::from_entropy())),
+            touched_info_sets: Arc::new(Mutex::new(HashSet::new())),
             total_simulations: std::sync::atomic::AtomicU64::new(0),
+            opponent_model: None,
         }
     }
 
+    /// Installe le modèle d'adversaire à utiliser pour l'équité simulée et
+    /// l'échantillonnage adverse de la traversée CFR.
+    pub fn with_opponent_model(mut self, model: Arc<dyn OpponentModel + Send + Sync>) -> Self {
+        self.opponent_model = Some(model);
+        self
+    }
+
     /// 🚀 SIMULATION MONTE CARLO ULTRA-RAPIDE (vs Python 100x plus lent)
     pub fn calculate_win_probability_fast(&mut self, 
         state: &PokerState, 
@@ -73,30 +95,58 @@ impl FullCfrEngine {
     }
 
     /// 🔥 SIMULATION INDIVIDUELLE OPTIMISÉE
+    ///
+    /// Distribue les cartes adverses et le run-out manquant depuis un
+    /// `Deck` excluant les cartes déjà connues (pas de doublon/collision),
+    /// puis départage sur le rang exact (`hand_eval::evaluate_hand_rank`)
+    /// plutôt que sur des "forces" tirées au hasard. Quand un
+    /// `opponent_model` est installé, chaque adversaire peut se coucher
+    /// avant le showdown selon sa probabilité de fold modélisée plutôt que
+    /// d'aller systématiquement à l'abattage.
     fn simulate_hand_fast(&self, state: &PokerState, rng: &mut ThreadRng) -> bool {
-        // ⚡ Évaluation directe des mains optimisée
-        let hero_strength = Self::evaluate_hand_strength_fast(
-            &state.hole_cards, 
-            &state.community_cards
-        );
-        
-        // Simulation des adversaires avec distribution réaliste
-        let opponents_strength: Vec<f64> = (0..state.num_players - 1)
-            .map(|_| {
-                // Distribution d'adversaires basée sur profils de jeu
-                let base_strength = rng.gen::<f64>() * 0.6 + 0.2; // 0.2-0.8
-                let skill_adjustment = match state.betting_round {
-                    BettingRound::Preflop => 0.0,
-                    BettingRound::Flop => rng.gen::<f64>() * 0.1,
-                    BettingRound::Turn => rng.gen::<f64>() * 0.15,
-                    BettingRound::River => rng.gen::<f64>() * 0.2,
-                };
-                base_strength + skill_adjustment
-            })
+        let mut known = state.hole_cards.clone();
+        known.extend_from_slice(&state.community_cards);
+        let mut deck = Deck::new_random(&known);
+
+        let opponents_hole: Vec<Vec<Card>> = (0..state.num_players.saturating_sub(1))
+            .map(|_| (0..2).filter_map(|_| deck.deal()).collect())
             .collect();
 
-        // Hero gagne si meilleur que tous les adversaires
-        opponents_strength.iter().all(|&opp_strength| hero_strength > opp_strength)
+        let mut board = state.community_cards.clone();
+        let missing_board = 5usize.saturating_sub(board.len());
+        board.extend((0..missing_board).filter_map(|_| deck.deal()));
+
+        let mut hero_cards = state.hole_cards.clone();
+        hero_cards.extend_from_slice(&board);
+        let hero_rank = hand_eval::evaluate_hand_rank(&hero_cards);
+
+        // Hero gagne si tous les adversaires se couchent ou sont battus au
+        // showdown.
+        opponents_hole.iter().all(|hole| {
+            if self.opponent_folds(state, rng) {
+                return true;
+            }
+            let mut villain_cards = hole.clone();
+            villain_cards.extend_from_slice(&board);
+            hero_rank > hand_eval::evaluate_hand_rank(&villain_cards)
+        })
+    }
+
+    /// Tire, via `opponent_model`, si l'adversaire se couche à `state`
+    /// plutôt que d'aller au showdown (toujours faux sans modèle installé).
+    fn opponent_folds(&self, state: &PokerState, rng: &mut ThreadRng) -> bool {
+        let model = match &self.opponent_model {
+            Some(model) => model,
+            None => return false,
+        };
+
+        let fold_prob = model
+            .action_distribution(state, &Self::get_legal_actions_fast(state))
+            .get(&Action::Fold)
+            .copied()
+            .unwrap_or(0.0);
+
+        rng.gen::<f64>() < fold_prob
     }
 
     /// 🚀 ÉVALUATION ULTRA-RAPIDE DES MAINS
@@ -171,11 +221,15 @@ impl FullCfrEngine {
     }
 
     /// 🔥 UPDATE CFR TABLES HAUTE PERFORMANCE
-    pub fn update_cfr_tables_batch(&mut self, states: &[PokerState]) -> f64 {
+    ///
+    /// `iteration` (1-indexée) pilote le schéma de pondération choisi par
+    /// `self.config.update_rule` (voir `apply_regret_update`/
+    /// `apply_strategy_update`): vanille, CFR+ ou Discounted-CFR.
+    pub fn update_cfr_tables_batch(&mut self, states: &[PokerState], iteration: usize) -> f64 {
         let convergence_sum: f64 = states
             .par_iter()  // Traitement parallèle
             .map(|state| {
-                self.update_single_state_cfr(state)
+                self.update_single_state_cfr(state, iteration)
             })
             .sum();
 
@@ -183,10 +237,10 @@ impl FullCfrEngine {
     }
 
     /// ⚡ CFR UPDATE POUR UN ÉTAT UNIQUE
-    fn update_single_state_cfr(&self, state: &PokerState) -> f64 {
+    fn update_single_state_cfr(&self, state: &PokerState, iteration: usize) -> f64 {
         let info_set = Self::get_information_set_fast(state);
         let actions = Self::get_legal_actions_fast(state);
-        
+
         if actions.is_empty() {
             return 0.0;
         }
@@ -201,30 +255,84 @@ impl FullCfrEngine {
             total_regret += regret.abs();
         }
 
-        // Mise à jour atomique des tables CFR
+        let params = self.config.update_params(iteration);
+
+        // Mise à jour atomique des tables CFR, selon le schéma de pondération
+        // configuré (vanille / CFR+ / Discounted)
         if let Ok(mut regret_sum) = self.regret_sum.lock() {
             let info_regrets = regret_sum.entry(info_set.clone())
                 .or_insert_with(HashMap::new);
-            
+
             for (action, regret) in &regrets {
-                *info_regrets.entry(action.clone()).or_insert(0.0) += regret;
+                let slot = info_regrets.entry(action.clone()).or_insert(0.0);
+                Self::apply_regret_update(slot, *regret, &params);
             }
         }
 
         // Calcul et stockage de la stratégie
         let strategy = self.get_strategy_from_regrets_fast(&info_set, &actions);
+        let strategy_weight = match params.rule {
+            // CFR+: moyennage linéaire, la contribution pèse davantage aux
+            // itérations tardives.
+            CfrUpdateRule::CfrPlus => params.iteration.max(1) as f64,
+            _ => 1.0,
+        };
         if let Ok(mut strategy_sum) = self.strategy_sum.lock() {
             let info_strategies = strategy_sum.entry(info_set)
                 .or_insert_with(HashMap::new);
-            
+
             for (action, prob) in strategy {
-                *info_strategies.entry(action).or_insert(0.0) += prob;
+                let slot = info_strategies.entry(action).or_insert(0.0);
+                Self::apply_strategy_update(slot, strategy_weight * prob, &params);
             }
         }
 
         total_regret
     }
 
+    /// Applique à `*slot` (une entrée de `regret_sum`) l'incrément
+    /// `instant_regret` selon le schéma de `params.rule`: accumulation brute
+    /// en vanille, plancher à 0 en CFR+, ou décote géométrique des regrets
+    /// positifs/négatifs (exposants `alpha`/`beta`) en Discounted CFR.
+    fn apply_regret_update(slot: &mut f64, instant_regret: f64, params: &UpdateParams) {
+        match params.rule {
+            CfrUpdateRule::Vanilla => {
+                *slot += instant_regret;
+            }
+            CfrUpdateRule::CfrPlus => {
+                *slot = (*slot + instant_regret).max(0.0);
+            }
+            CfrUpdateRule::Discounted => {
+                let t = params.iteration.max(1) as f64;
+                if *slot > 0.0 {
+                    *slot *= t.powf(params.alpha) / (t.powf(params.alpha) + 1.0);
+                } else if *slot < 0.0 {
+                    *slot *= t.powf(params.beta) / (t.powf(params.beta) + 1.0);
+                }
+                *slot += instant_regret;
+            }
+        }
+    }
+
+    /// Applique à `*slot` (une entrée de `strategy_sum`) la contribution
+    /// `weighted_contribution` selon `params.rule`: accumulation brute en
+    /// vanille/CFR+ (le moyennage linéaire de CFR+ est déjà porté par
+    /// `weighted_contribution`, voir l'appelant), ou décote géométrique par
+    /// `(t/(t+1))^gamma` en Discounted CFR.
+    fn apply_strategy_update(slot: &mut f64, weighted_contribution: f64, params: &UpdateParams) {
+        match params.rule {
+            CfrUpdateRule::Discounted => {
+                let t = params.iteration.max(1) as f64;
+                let factor = (t / (t + 1.0)).powf(params.gamma);
+                *slot *= factor;
+                *slot += weighted_contribution;
+            }
+            CfrUpdateRule::Vanilla | CfrUpdateRule::CfrPlus => {
+                *slot += weighted_contribution;
+            }
+        }
+    }
+
     /// 🚀 CALCUL REGRET ACTION OPTIMISÉ
     fn calculate_action_regret_fast(&self, state: &PokerState, action: &Action) -> f64 {
         // Estimation rapide EV basée sur simulation/heuristiques
@@ -398,9 +506,904 @@ impl FullCfrEngine {
         if let Ok(cache) = self.equity_cache.lock() {
             stats.insert("cache_size".to_string(), cache.len() as f64);
         }
-        
+
+        if let Ok(touched) = self.touched_info_sets.lock() {
+            stats.insert("touched_info_sets".to_string(), touched.len() as f64);
+        }
+
         stats
     }
+
+    /// Vide le compteur d'information sets touchés par le mode Monte-Carlo,
+    /// à appeler avant un batch d'itérations pour que `get_performance_stats`
+    /// ne rapporte que la couverture de ce batch plutôt qu'un cumul depuis
+    /// la création de l'engine.
+    pub fn reset_touched_info_sets(&self) {
+        if let Ok(mut touched) = self.touched_info_sets.lock() {
+            touched.clear();
+        }
+    }
+}
+
+/// Modèle d'adversaire enfichable: remplace le bruit uniforme de
+/// `FullCfrEngine::simulate_hand_fast` et de l'échantillonnage adverse de
+/// `FullCfrEngine::cfr_external_sampling` par une distribution d'actions
+/// qui reflète un comportement modélisé plutôt qu'aléatoire.
+pub trait OpponentModel {
+    /// Distribution de probabilité sur les actions légales de `state`
+    /// (somme à 1 si `state` a au moins une action légale).
+    fn action_distribution(&self, state: &PokerState, legal_actions: &[Action]) -> HashMap<Action, f64>;
+}
+
+/// 🧠 MODÈLE D'ADVERSAIRE PAR Q-LEARNING TABULAIRE
+///
+/// Table `info_set -> {action -> valeur}` mise à jour en ligne par
+/// `Q(s,a) += lr*(reward - Q(s,a))` à partir de gains de showdown simulés
+/// (voir `update`), avec sélection ε-greedy: exploite l'action de plus
+/// grande valeur connue, sauf exploration uniforme avec probabilité
+/// `epsilon`.
+pub struct QLearningOpponentModel {
+    q_table: Arc<Mutex<HashMap<String, HashMap<Action, f64>>>>,
+    learning_rate: f64,
+    epsilon: f64,
+    rng: Arc<Mutex<StdRng>>,
+}
+
+impl QLearningOpponentModel {
+    pub fn new(learning_rate: f64, epsilon: f64) -> Self {
+        Self {
+            q_table: Arc::new(Mutex::new(HashMap::new())),
+            learning_rate,
+            epsilon,
+            rng: Arc::new(Mutex::new(StdRng::from_entropy())),
+        }
+    }
+
+    /// Met à jour `Q(info_set, action)` vers `reward` (gain de showdown
+    /// simulé ou réel observé) par le pas `learning_rate`.
+    pub fn update(&self, info_set: &str, action: &Action, reward: f64) {
+        if let Ok(mut q_table) = self.q_table.lock() {
+            let entry = q_table.entry(info_set.to_string()).or_insert_with(HashMap::new);
+            let slot = entry.entry(action.clone()).or_insert(0.0);
+            *slot += self.learning_rate * (reward - *slot);
+        }
+    }
+}
+
+impl OpponentModel for QLearningOpponentModel {
+    fn action_distribution(&self, state: &PokerState, legal_actions: &[Action]) -> HashMap<Action, f64> {
+        if legal_actions.is_empty() {
+            return HashMap::new();
+        }
+
+        let uniform_prob = 1.0 / legal_actions.len() as f64;
+        let uniform = || legal_actions.iter().map(|action| (action.clone(), uniform_prob)).collect();
+
+        let explore = self.rng.lock()
+            .map(|mut rng| rng.gen::<f64>() < self.epsilon)
+            .unwrap_or(false);
+        if explore {
+            return uniform();
+        }
+
+        let info_set = FullCfrEngine::get_information_set_fast(state);
+        let q_table = match self.q_table.lock() {
+            Ok(q_table) => q_table,
+            Err(_) => return uniform(),
+        };
+        let values = q_table.get(&info_set);
+
+        let best_action = legal_actions.iter().max_by(|a, b| {
+            let qa = values.and_then(|v| v.get(*a)).copied().unwrap_or(0.0);
+            let qb = values.and_then(|v| v.get(*b)).copied().unwrap_or(0.0);
+            qa.partial_cmp(&qb).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        match best_action {
+            Some(best) => legal_actions.iter()
+                .map(|action| (action.clone(), if action == best { 1.0 } else { 0.0 }))
+                .collect(),
+            None => uniform(),
+        }
+    }
+}
+
+/// 🧬 MODÈLE D'ADVERSAIRE HEURISTIQUE À POIDS ÉVOLUÉS
+///
+/// Score une décision par une combinaison linéaire de quatre traits
+/// (force de main, cotes du pot, position, ratio mise/pot), puis convertit
+/// ce score en distribution sur les actions légales: plus le score est haut,
+/// plus les relances/mises pèsent par rapport au fold. Les poids eux-mêmes
+/// sont calibrés hors ligne par `evolve_weights` plutôt que choisis à la main.
+#[derive(Debug, Clone)]
+pub struct HeuristicOpponentModel {
+    /// [force_de_main, cotes_du_pot, position, ratio_mise_pot]
+    pub weights: [f64; 4],
+}
+
+impl HeuristicOpponentModel {
+    pub fn new(weights: [f64; 4]) -> Self {
+        Self { weights }
+    }
+
+    /// Score d'agressivité: combinaison linéaire des quatre traits normalisés
+    /// dans `[0, 1]`.
+    fn score(&self, state: &PokerState) -> f64 {
+        let hand_strength =
+            FullCfrEngine::evaluate_hand_strength_fast(&state.hole_cards, &state.community_cards);
+        let pot_odds = if state.pot_size > 0.0 {
+            state.pot_size / (state.pot_size + state.stack_size.max(1.0))
+        } else {
+            0.0
+        };
+        let position = if state.num_players > 1 {
+            state.position as f64 / (state.num_players - 1) as f64
+        } else {
+            0.0
+        };
+        let bet_to_pot = if state.pot_size > 0.0 {
+            (state.stack_size.min(state.pot_size) / state.pot_size).min(1.0)
+        } else {
+            0.0
+        };
+
+        self.weights[0] * hand_strength
+            + self.weights[1] * pot_odds
+            + self.weights[2] * position
+            + self.weights[3] * bet_to_pot
+    }
+
+    /// Calibre `weights` par un cycle évolutif simple de `generations`
+    /// générations: chaque génération mute `population_size` vecteurs de
+    /// poids autour des survivants de la génération précédente, évalue leur
+    /// gain moyen sur `simulate_hand_fast` de `engine` pour `states`, puis ne
+    /// garde que la moitié la plus performante pour muter la génération
+    /// suivante (auto-jeu: plus un vecteur de poids "adversaire" gagne
+    /// contre l'estimation d'équité de l'engine, plus il survit).
+    pub fn evolve_weights(
+        engine: &mut FullCfrEngine,
+        states: &[PokerState],
+        population_size: usize,
+        generations: usize,
+        simulations_per_eval: usize,
+    ) -> Self {
+        let mut rng = thread_rng();
+        let mut population: Vec<[f64; 4]> = (0..population_size.max(2))
+            .map(|_| std::array::from_fn(|_| rng.gen_range(-1.0..1.0)))
+            .collect();
+
+        for _ in 0..generations.max(1) {
+            let mut scored: Vec<([f64; 4], f64)> = population.iter()
+                .map(|&weights| {
+                    let model = HeuristicOpponentModel::new(weights);
+                    let fitness: f64 = states.iter()
+                        .map(|state| {
+                            engine.opponent_model = Some(Arc::new(model.clone()));
+                            1.0 - engine.calculate_win_probability_fast(state, simulations_per_eval)
+                        })
+                        .sum::<f64>() / states.len().max(1) as f64;
+                    (weights, fitness)
+                })
+                .collect();
+
+            scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            let survivors: Vec<[f64; 4]> = scored.into_iter()
+                .take((population_size.max(2)) / 2)
+                .map(|(weights, _)| weights)
+                .collect();
+
+            population = survivors.iter()
+                .flat_map(|&weights| {
+                    (0..2).map(move |_| {
+                        let mut mutated = weights;
+                        for w in mutated.iter_mut() {
+                            *w += thread_rng().gen_range(-0.1..0.1);
+                        }
+                        mutated
+                    })
+                })
+                .collect();
+        }
+
+        let best = population.into_iter().next().unwrap_or([0.25; 4]);
+        engine.opponent_model = None;
+        HeuristicOpponentModel::new(best)
+    }
+}
+
+impl OpponentModel for HeuristicOpponentModel {
+    fn action_distribution(&self, state: &PokerState, legal_actions: &[Action]) -> HashMap<Action, f64> {
+        if legal_actions.is_empty() {
+            return HashMap::new();
+        }
+
+        let score = self.score(state).clamp(0.0, 1.0);
+
+        // Poids brut par action: le fold pèse l'inverse du score, les mises/
+        // relances pèsent le score lui-même, check/call restent neutres.
+        let raw_weights: Vec<f64> = legal_actions.iter()
+            .map(|action| match action {
+                Action::Fold => (1.0 - score).max(0.05),
+                Action::Raise(_) | Action::Bet(_) | Action::AllIn => score.max(0.05),
+                Action::Call | Action::Check => 0.5,
+            })
+            .collect();
+
+        let total: f64 = raw_weights.iter().sum();
+        legal_actions.iter().cloned()
+            .zip(raw_weights.iter().map(|&w| w / total))
+            .collect()
+    }
+}
+
+/// 🌳 NOEUD DE L'ARBRE CFR (arène `Vec<CfrNode>`, adressage par indice)
+///
+/// Les noeuds sont stockés à plat dans `CfrTree::nodes` plutôt qu'au
+/// travers de pointeurs boxés: une main entière s'alloue en un seul `Vec`
+/// et se retraverse via de simples `usize`, ce qui permet au `Historian`
+/// de revisiter le même arbre d'une main à l'autre sans le reconstruire.
+#[derive(Debug, Clone)]
+pub enum CfrNode {
+    /// Noeud de hasard (changement de street): une seule issue échantillonnée
+    /// par noeud (convention MCCFR déjà utilisée par
+    /// `CfrEngine::cfr_external_sampling`), pondérée par
+    /// `Deck::sampling_weight` pour la portée ("reach") des descendants.
+    Chance { outcomes: Vec<(usize, f64)> },
+    /// Noeud de décision: clé d'information set, actions légales et
+    /// l'indice d'enfant associé à chacune (même ordre que `actions`).
+    Player {
+        info_set: String,
+        actions: Vec<Action>,
+        children: Vec<usize>,
+        /// 0 = héros, 1 = adversaire (heads-up, alterne à chaque street)
+        acting: u8,
+        /// État d'origine de ce noeud, conservé pour les modèles d'adversaire
+        /// enfichables (`OpponentModel`) qui ont besoin des traits bruts
+        /// (cotes du pot, position, ...) au-delà de la seule clé abstraite
+        /// `info_set`.
+        state: PokerState,
+    },
+    /// Noeud terminal: gain déjà résolu pour le joueur 0 (héros), que ce
+    /// soit un fold adverse/héros ou un vrai showdown 7 cartes.
+    Terminal { payoff: f64 },
+}
+
+/// Arène de l'arbre de jeu CFR pour `FullCfrEngine`: construite une fois
+/// par état racine via `build`, puis parcourue à chaque itération par
+/// `FullCfrEngine::cfr` sans recréer de nouveaux états intermédiaires.
+pub struct CfrTree {
+    nodes: Vec<CfrNode>,
+}
+
+impl CfrTree {
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    fn push(&mut self, node: CfrNode) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    pub fn node(&self, idx: usize) -> &CfrNode {
+        &self.nodes[idx]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Construit l'arbre complet pour `state` (héros au trait en premier)
+    /// jusqu'aux terminaisons, et retourne l'indice de sa racine.
+    pub fn build(&mut self, state: &PokerState) -> usize {
+        self.build_node(state, 0)
+    }
+
+    fn build_node(&mut self, state: &PokerState, acting: u8) -> usize {
+        if state.folded {
+            let payoff = if acting == 0 { -state.pot_size } else { state.pot_size };
+            return self.push(CfrNode::Terminal { payoff });
+        }
+
+        let actions = FullCfrEngine::get_legal_actions_fast(state);
+        if actions.is_empty() {
+            return self.push(CfrNode::Terminal { payoff: Self::showdown_payoff(state) });
+        }
+
+        let info_set = FullCfrEngine::get_information_set_fast(state);
+        let mut children = Vec::with_capacity(actions.len());
+
+        for action in &actions {
+            let applied = FullCfrEngine::apply_action_for_tree(state, action);
+            let child_idx = if applied.folded {
+                let payoff = if acting == 0 { -applied.pot_size } else { applied.pot_size };
+                self.push(CfrNode::Terminal { payoff })
+            } else if state.betting_round == BettingRound::River {
+                self.push(CfrNode::Terminal { payoff: Self::showdown_payoff(&applied) })
+            } else {
+                self.push_street_transition(&applied, acting)
+            };
+            children.push(child_idx);
+        }
+
+        self.push(CfrNode::Player { info_set, actions, children, acting, state: state.clone() })
+    }
+
+    /// Intercale un noeud `Chance` entre le round de mise qui vient de se
+    /// terminer et le prochain noeud `Player`: tire la prochaine carte
+    /// communautaire depuis le deck partagé du state (sans doublon), puis
+    /// recurse sur le joueur opposé au round suivant.
+    fn push_street_transition(&mut self, state: &PokerState, acting: u8) -> usize {
+        let mut next = state.clone();
+        let mut known = next.hole_cards.clone();
+        known.extend_from_slice(&next.community_cards);
+        let mut deck = next.deck.clone().unwrap_or_else(|| Deck::new_random(&known));
+
+        let (round, dealt) = match next.betting_round {
+            BettingRound::Preflop => (BettingRound::Flop, 3),
+            BettingRound::Flop => (BettingRound::Turn, 1),
+            BettingRound::Turn => (BettingRound::River, 1),
+            BettingRound::River => (BettingRound::River, 0),
+        };
+
+        let weight = deck.sampling_weight(dealt);
+        next.community_cards.extend((0..dealt).filter_map(|_| deck.deal()));
+        next.betting_round = round;
+        next.deck = Some(deck);
+
+        let child_idx = self.build_node(&next, 1 - acting);
+        self.push(CfrNode::Chance { outcomes: vec![(child_idx, weight)] })
+    }
+
+    /// Gain de showdown pour le joueur 0 (héros): tire la main adverse et le
+    /// run-out restant depuis le deck partagé du state (sans collision),
+    /// même logique que `FullCfrEngine::simulate_hand_fast` mais un tirage
+    /// unique plutôt qu'une moyenne Monte-Carlo.
+    fn showdown_payoff(state: &PokerState) -> f64 {
+        let mut known = state.hole_cards.clone();
+        known.extend_from_slice(&state.community_cards);
+        let mut deck = state.deck.clone().unwrap_or_else(|| Deck::new_random(&known));
+
+        let villain_hole: Vec<Card> = (0..2).filter_map(|_| deck.deal()).collect();
+        let mut board = state.community_cards.clone();
+        board.extend((0..5usize.saturating_sub(board.len())).filter_map(|_| deck.deal()));
+
+        let mut hero_cards = state.hole_cards.clone();
+        hero_cards.extend_from_slice(&board);
+        let mut villain_cards = villain_hole;
+        villain_cards.extend_from_slice(&board);
+
+        let hero_rank = hand_eval::evaluate_hand_rank(&hero_cards);
+        let villain_rank = hand_eval::evaluate_hand_rank(&villain_cards);
+
+        match hero_rank.cmp(&villain_rank) {
+            std::cmp::Ordering::Greater => state.pot_size,
+            std::cmp::Ordering::Less => -state.pot_size,
+            std::cmp::Ordering::Equal => 0.0,
+        }
+    }
+}
+
+/// 🕵️ HISTORIAN — retrouve un noeud de l'arène depuis une séquence d'actions
+///
+/// Observe les `Action`s jouées main après main et retraverse un arbre déjà
+/// construit par `CfrTree::build` au lieu d'en bâtir un nouveau à chaque main
+/// (`FullCfrTrainer::train_intensive_batch` construit ses propres arbres à
+/// chaque appel et ne les expose pas), ce qui permet de réentraîner sur le même arbre
+/// (et donc les mêmes indices d'information set) d'une main à l'autre.
+pub struct Historian {
+    root: usize,
+}
+
+impl Historian {
+    pub fn new(root: usize) -> Self {
+        Self { root }
+    }
+
+    /// Retrouve l'indice du noeud atteint après `history` en partant de la
+    /// racine. Retourne `None` si une action ne correspond à aucun enfant
+    /// connu (divergence entre l'abstraction actuelle et l'historique, ex.
+    /// un changement de `CfrConfig::abstraction_buckets` entre deux runs).
+    pub fn walk(&self, tree: &CfrTree, history: &[Action]) -> Option<usize> {
+        let mut current = self.root;
+        for action in history {
+            match tree.node(current) {
+                CfrNode::Player { actions, children, .. } => {
+                    let pos = actions.iter().position(|a| a == action)?;
+                    current = children[pos];
+                }
+                CfrNode::Chance { outcomes } => {
+                    // Un seul résultat échantillonné par noeud de hasard: on
+                    // le retraverse indépendamment de l'action observée.
+                    current = outcomes.first()?.0;
+                }
+                CfrNode::Terminal { .. } => return None,
+            }
+        }
+        Some(current)
+    }
+}
+
+impl FullCfrEngine {
+    /// Applique les effets immédiats de `action` (pot/stack, fold) sans
+    /// faire avancer la street: `CfrTree::build_node` intercale lui-même un
+    /// noeud `Chance` entre deux noeuds `Player` quand le round se termine.
+    fn apply_action_for_tree(state: &PokerState, action: &Action) -> PokerState {
+        let mut next = state.clone();
+        match action {
+            Action::Fold => {
+                next.folded = true;
+            }
+            Action::Raise(amount) | Action::Bet(amount) => {
+                next.pot_size += amount;
+                next.stack_size -= amount;
+            }
+            Action::AllIn => {
+                next.pot_size += next.stack_size;
+                next.stack_size = 0.0;
+            }
+            Action::Call | Action::Check => {}
+        }
+        next
+    }
+
+    /// 🌳 PARCOURS CFR RÉCURSIF SUR L'ARÈNE (véritable regret minimization)
+    ///
+    /// À chaque noeud `Player`, calcule la stratégie courante par regret
+    /// matching (`get_strategy_from_regrets_fast`), recurse dans chaque
+    /// enfant pour obtenir la valeur contrefactuelle de l'action, accumule
+    /// la valeur du noeud pondérée par la stratégie, puis met à jour
+    /// `regret_sum` par `portée_contrefactuelle * (valeur_action -
+    /// valeur_noeud)` et `strategy_sum` par `portée_propre * stratégie[a]`.
+    /// `reach_probs[0]`/`[1]` sont les probabilités d'atteinte du héros et de
+    /// l'adversaire; le hasard se replie dans les deux au passage d'un
+    /// noeud `Chance`.
+    /// `iteration` (1-indexée) est repassée inchangée à chaque récursion et
+    /// pilote le schéma de pondération configuré via `self.config.update_rule`
+    /// (vanille / CFR+ / Discounted), par les mêmes `apply_regret_update`/
+    /// `apply_strategy_update` que `update_single_state_cfr`.
+    pub fn cfr(&self, tree: &CfrTree, node_idx: usize, reach_probs: [f64; 2], iteration: usize) -> f64 {
+        match tree.node(node_idx) {
+            CfrNode::Terminal { payoff } => *payoff,
+
+            CfrNode::Chance { outcomes } => {
+                let (child, weight) = outcomes[0];
+                let child_reach = [reach_probs[0] * weight, reach_probs[1] * weight];
+                self.cfr(tree, child, child_reach, iteration)
+            }
+
+            CfrNode::Player { info_set, actions, children, acting, .. } => {
+                let acting = *acting as usize;
+                let strategy = self.get_strategy_from_regrets_fast(info_set, actions);
+
+                let action_values: Vec<f64> = children
+                    .iter()
+                    .zip(actions)
+                    .map(|(&child, action)| {
+                        let mut child_reach = reach_probs;
+                        child_reach[acting] *= strategy.get(action).copied().unwrap_or(0.0);
+                        self.cfr(tree, child, child_reach, iteration)
+                    })
+                    .collect();
+
+                let node_value: f64 = actions.iter().zip(&action_values)
+                    .map(|(action, &value)| strategy.get(action).copied().unwrap_or(0.0) * value)
+                    .sum();
+
+                let counterfactual_reach = reach_probs[1 - acting];
+                let own_reach = reach_probs[acting];
+
+                let params = self.config.update_params(iteration);
+                let strategy_weight = match params.rule {
+                    CfrUpdateRule::CfrPlus => params.iteration.max(1) as f64,
+                    _ => 1.0,
+                };
+
+                if let Ok(mut regret_sum) = self.regret_sum.lock() {
+                    let entry = regret_sum.entry(info_set.clone()).or_insert_with(HashMap::new);
+                    for (action, &value) in actions.iter().zip(&action_values) {
+                        let slot = entry.entry(action.clone()).or_insert(0.0);
+                        Self::apply_regret_update(slot, counterfactual_reach * (value - node_value), &params);
+                    }
+                }
+
+                if let Ok(mut strategy_sum) = self.strategy_sum.lock() {
+                    let entry = strategy_sum.entry(info_set.clone()).or_insert_with(HashMap::new);
+                    for action in actions {
+                        let prob = strategy.get(action).copied().unwrap_or(0.0);
+                        let slot = entry.entry(action.clone()).or_insert(0.0);
+                        Self::apply_strategy_update(slot, strategy_weight * own_reach * prob, &params);
+                    }
+                }
+
+                node_value
+            }
+        }
+    }
+
+    /// Tire un indice dans `0..weights.len()` proportionnellement à
+    /// `weights` (non nécessairement normalisés) via `self.rng`.
+    fn sample_index(weights: &[f64], rng: &Arc<Mutex<StdRng>>) -> usize {
+        let total: f64 = weights.iter().sum();
+        let threshold = {
+            let mut rng = rng.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            rng.gen::<f64>() * total.max(f64::MIN_POSITIVE)
+        };
+
+        let mut cumulative = 0.0;
+        for (idx, &weight) in weights.iter().enumerate() {
+            cumulative += weight;
+            if threshold <= cumulative {
+                return idx;
+            }
+        }
+        weights.len() - 1
+    }
+
+    /// Échantillonne une issue d'un noeud `Chance` proportionnellement à son
+    /// poids. `CfrTree::build` ne stocke aujourd'hui qu'une seule issue par
+    /// noeud, donc ce tirage est actuellement déterministe, mais la méthode
+    /// reste correcte si l'arène venait à stocker plusieurs issues.
+    fn sample_outcome(outcomes: &[(usize, f64)], rng: &Arc<Mutex<StdRng>>) -> (usize, f64) {
+        if outcomes.len() == 1 {
+            return outcomes[0];
+        }
+        let weights: Vec<f64> = outcomes.iter().map(|&(_, weight)| weight).collect();
+        outcomes[Self::sample_index(&weights, rng)]
+    }
+
+    /// 🎲 MONTE-CARLO CFR — OUTCOME SAMPLING (`SamplingMode::OutcomeSampling`)
+    ///
+    /// N'échantillonne qu'une seule action à chaque noeud joueur (mélange
+    /// ε-exploratoire `(1-ε)*strategy[a] + ε/|actions|` de la stratégie
+    /// courante) et une seule issue à chaque noeud de hasard, pour n'atteindre
+    /// qu'une seule terminaison par appel au lieu de l'arbre complet. Les
+    /// regrets/stratégie ne sont mis à jour que le long de ce chemin, avec une
+    /// estimée non biaisée obtenue en divisant la valeur remontée par la
+    /// probabilité d'échantillonnage de chaque arête traversée (pondération
+    /// d'importance, cf. Lanctot et al. 2009).
+    pub fn cfr_outcome_sampling(&self, tree: &CfrTree, node_idx: usize, iteration: usize) -> f64 {
+        const EPSILON_EXPLORATION: f64 = 0.6;
+
+        match tree.node(node_idx) {
+            CfrNode::Terminal { payoff } => *payoff,
+
+            CfrNode::Chance { outcomes } => {
+                let (child, weight) = Self::sample_outcome(outcomes, &self.rng);
+                self.cfr_outcome_sampling(tree, child, iteration) / weight.max(f64::MIN_POSITIVE)
+            }
+
+            CfrNode::Player { info_set, actions, children, .. } => {
+                if let Ok(mut touched) = self.touched_info_sets.lock() {
+                    touched.insert(info_set.clone());
+                }
+
+                let strategy = self.get_strategy_from_regrets_fast(info_set, actions);
+                let num_actions = actions.len() as f64;
+                let sampling_probs: Vec<f64> = actions.iter()
+                    .map(|action| {
+                        (1.0 - EPSILON_EXPLORATION) * strategy.get(action).copied().unwrap_or(0.0)
+                            + EPSILON_EXPLORATION / num_actions
+                    })
+                    .collect();
+
+                let sampled_idx = Self::sample_index(&sampling_probs, &self.rng);
+                let sample_prob = sampling_probs[sampled_idx].max(f64::MIN_POSITIVE);
+                let child_value =
+                    self.cfr_outcome_sampling(tree, children[sampled_idx], iteration) / sample_prob;
+
+                let params = self.config.update_params(iteration);
+                if let Ok(mut regret_sum) = self.regret_sum.lock() {
+                    let entry = regret_sum.entry(info_set.clone()).or_insert_with(HashMap::new);
+                    for (idx, action) in actions.iter().enumerate() {
+                        let indicator = if idx == sampled_idx { 1.0 } else { 0.0 };
+                        let prob = strategy.get(action).copied().unwrap_or(0.0);
+                        let slot = entry.entry(action.clone()).or_insert(0.0);
+                        Self::apply_regret_update(slot, child_value * (indicator - prob), &params);
+                    }
+                }
+
+                if let Ok(mut strategy_sum) = self.strategy_sum.lock() {
+                    let entry = strategy_sum.entry(info_set.clone()).or_insert_with(HashMap::new);
+                    for action in actions {
+                        let prob = strategy.get(action).copied().unwrap_or(0.0);
+                        let slot = entry.entry(action.clone()).or_insert(0.0);
+                        Self::apply_strategy_update(slot, prob, &params);
+                    }
+                }
+
+                child_value
+            }
+        }
+    }
+
+    /// 🎲 MONTE-CARLO CFR — EXTERNAL SAMPLING (`SamplingMode::ExternalSampling`)
+    ///
+    /// Le joueur `traverser` explore exhaustivement toutes ses actions et met
+    /// à jour ses regrets comme en CFR vanille (`cfr`); aux noeuds de
+    /// l'adversaire et de hasard, une seule branche est échantillonnée selon
+    /// la stratégie/le deck et seule elle est visitée, rendant le coût d'une
+    /// itération linéaire en profondeur plutôt qu'exponentiel.
+    pub fn cfr_external_sampling(
+        &self,
+        tree: &CfrTree,
+        node_idx: usize,
+        reach_probs: [f64; 2],
+        traverser: u8,
+        iteration: usize,
+    ) -> f64 {
+        match tree.node(node_idx) {
+            CfrNode::Terminal { payoff } => *payoff,
+
+            CfrNode::Chance { outcomes } => {
+                let (child, weight) = Self::sample_outcome(outcomes, &self.rng);
+                let child_reach = [reach_probs[0] * weight, reach_probs[1] * weight];
+                self.cfr_external_sampling(tree, child, child_reach, traverser, iteration)
+            }
+
+            CfrNode::Player { info_set, actions, children, acting, state } => {
+                if let Ok(mut touched) = self.touched_info_sets.lock() {
+                    touched.insert(info_set.clone());
+                }
+
+                let strategy = self.get_strategy_from_regrets_fast(info_set, actions);
+                let params = self.config.update_params(iteration);
+
+                if *acting == traverser {
+                    let action_values: Vec<f64> = children.iter().zip(actions)
+                        .map(|(&child, action)| {
+                            let mut child_reach = reach_probs;
+                            child_reach[traverser as usize] *= strategy.get(action).copied().unwrap_or(0.0);
+                            self.cfr_external_sampling(tree, child, child_reach, traverser, iteration)
+                        })
+                        .collect();
+
+                    let node_value: f64 = actions.iter().zip(&action_values)
+                        .map(|(action, &value)| strategy.get(action).copied().unwrap_or(0.0) * value)
+                        .sum();
+
+                    let counterfactual_reach = reach_probs[1 - traverser as usize];
+                    if let Ok(mut regret_sum) = self.regret_sum.lock() {
+                        let entry = regret_sum.entry(info_set.clone()).or_insert_with(HashMap::new);
+                        for (action, &value) in actions.iter().zip(&action_values) {
+                            let slot = entry.entry(action.clone()).or_insert(0.0);
+                            Self::apply_regret_update(slot, counterfactual_reach * (value - node_value), &params);
+                        }
+                    }
+
+                    node_value
+                } else {
+                    // Un `opponent_model` enfichable remplace ici la stratégie
+                    // de regret-matching auto-jouée par un comportement
+                    // adverse modélisé, afin que l'échantillonnage (et donc
+                    // l'estimée d'équité/fold-equity qui en découle) reflète
+                    // ce modèle plutôt que du bruit.
+                    let weights: Vec<f64> = match &self.opponent_model {
+                        Some(model) => {
+                            let distribution = model.action_distribution(state, actions);
+                            actions.iter().map(|action| distribution.get(action).copied().unwrap_or(0.0)).collect()
+                        }
+                        None => actions.iter()
+                            .map(|action| strategy.get(action).copied().unwrap_or(0.0))
+                            .collect(),
+                    };
+                    let sampled_idx = Self::sample_index(&weights, &self.rng);
+                    let sampled_prob = weights[sampled_idx].max(f64::MIN_POSITIVE);
+
+                    if let Ok(mut strategy_sum) = self.strategy_sum.lock() {
+                        let entry = strategy_sum.entry(info_set.clone()).or_insert_with(HashMap::new);
+                        let slot = entry.entry(actions[sampled_idx].clone()).or_insert(0.0);
+                        Self::apply_strategy_update(slot, sampled_prob, &params);
+                    }
+
+                    let mut child_reach = reach_probs;
+                    child_reach[*acting as usize] *= sampled_prob;
+                    self.cfr_external_sampling(tree, children[sampled_idx], child_reach, traverser, iteration)
+                }
+            }
+        }
+    }
+}
+
+/// Snapshot figé de `FullCfrEngine`: `id` est un hash de contenu déterministe
+/// du snapshot et `parent_id` pointe vers le snapshot dont celui-ci a été
+/// repris ou bifurqué (`None` pour un premier checkpoint). Deux snapshots
+/// de contenu identique obtiennent toujours le même `id`, ce qui permet de
+/// vérifier une lignée (reprise, fork, comparaison de deux checkpoints)
+/// sans dépendre d'horodatages.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CfrSnapshot {
+    pub id: u64,
+    pub parent_id: Option<u64>,
+    pub iterations_completed: usize,
+    pub total_simulations: u64,
+    pub regret_sum: BTreeMap<String, HashMap<Action, f64>>,
+    pub strategy_sum: BTreeMap<String, HashMap<Action, f64>>,
+}
+
+impl CfrSnapshot {
+    /// Hash de contenu FNV-like (mêmes constantes que
+    /// `FullCfrEngine::hash_cards_fast`) sur tous les champs du snapshot,
+    /// dans un ordre déterministe (les `BTreeMap` trient déjà leurs clés).
+    fn content_hash(
+        parent_id: Option<u64>,
+        iterations_completed: usize,
+        total_simulations: u64,
+        regret_sum: &BTreeMap<String, HashMap<Action, f64>>,
+        strategy_sum: &BTreeMap<String, HashMap<Action, f64>>,
+    ) -> u64 {
+        let mut hash = 0u64;
+        let mut mix = |bytes: &[u8]| {
+            for &b in bytes {
+                hash = hash.wrapping_mul(1099511628211);
+                hash ^= b as u64;
+            }
+        };
+
+        mix(&parent_id.unwrap_or(0).to_le_bytes());
+        mix(&(iterations_completed as u64).to_le_bytes());
+        mix(&total_simulations.to_le_bytes());
+
+        for table in [regret_sum, strategy_sum] {
+            for (info_set, actions) in table {
+                mix(info_set.as_bytes());
+                let mut entries: Vec<(String, f64)> = actions
+                    .iter()
+                    .map(|(action, value)| (format!("{:?}", action), *value))
+                    .collect();
+                entries.sort_by(|a, b| a.0.cmp(&b.0));
+                for (action_key, value) in entries {
+                    mix(action_key.as_bytes());
+                    mix(&value.to_bits().to_le_bytes());
+                }
+            }
+        }
+
+        hash
+    }
+}
+
+impl FullCfrEngine {
+    /// 📸 Gèle l'état courant (regrets, stratégie cumulée, compteurs) en un
+    /// `CfrSnapshot`, en référençant `parent_id` pour chaîner ce checkpoint à
+    /// celui dont il reprend ou bifurque.
+    pub fn freeze(&self, parent_id: Option<u64>, iterations_completed: usize) -> CfrSnapshot {
+        let regret_sum = self.regret_sum.lock().map(|g| g.clone()).unwrap_or_default();
+        let strategy_sum = self.strategy_sum.lock().map(|g| g.clone()).unwrap_or_default();
+        let total_simulations = self.total_simulations.load(std::sync::atomic::Ordering::Relaxed);
+
+        let id = CfrSnapshot::content_hash(
+            parent_id,
+            iterations_completed,
+            total_simulations,
+            &regret_sum,
+            &strategy_sum,
+        );
+
+        CfrSnapshot {
+            id,
+            parent_id,
+            iterations_completed,
+            total_simulations,
+            regret_sum,
+            strategy_sum,
+        }
+    }
+
+    /// Sérialise `snapshot` en JSON lisible à `path` (round-trippable par
+    /// `from_snapshot`), à l'image de `CfrEngine::export_data`.
+    pub fn save_snapshot(snapshot: &CfrSnapshot, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(path, serde_json::to_string_pretty(snapshot)?)?;
+        Ok(())
+    }
+
+    /// Reconstruit un engine à partir d'un fichier de snapshot produit par
+    /// `save_snapshot` et restaure ses tables/compteurs. `config` n'est pas
+    /// figée dans le snapshot: elle reste au choix de l'appelant, qui peut
+    /// ainsi reprendre un entraînement avec des hyperparamètres ajustés.
+    pub fn from_snapshot(path: &Path, config: CfrConfig) -> Result<(Self, CfrSnapshot), Box<dyn std::error::Error>> {
+        let data = std::fs::read_to_string(path)?;
+        let snapshot: CfrSnapshot = serde_json::from_str(&data)?;
+
+        let engine = Self::new(config);
+        if let Ok(mut regret_sum) = engine.regret_sum.lock() {
+            *regret_sum = snapshot.regret_sum.clone();
+        }
+        if let Ok(mut strategy_sum) = engine.strategy_sum.lock() {
+            *strategy_sum = snapshot.strategy_sum.clone();
+        }
+        engine.total_simulations.store(snapshot.total_simulations, std::sync::atomic::Ordering::Relaxed);
+
+        Ok((engine, snapshot))
+    }
+}
+
+/// Un pas de rejeu: l'état visité, ses actions légales et la stratégie
+/// moyenne convergée à son information set (vide si jamais visité par
+/// l'entraînement).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandReplayStep {
+    pub state: PokerState,
+    pub legal_actions: Vec<Action>,
+    pub info_set: String,
+    pub average_strategy: HashMap<Action, f64>,
+}
+
+/// Rejeu complet d'une main: la séquence de `HandReplayStep` visités et le
+/// gain final réalisé — un log auto-suffisant, outil-agnostique, pour
+/// analyse ou rejeu externe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandReplay {
+    pub steps: Vec<HandReplayStep>,
+    pub realized_outcome: f64,
+}
+
+/// Journal de mains en continu (une ligne JSON par main, format JSON Lines),
+/// pour écrire de gros batches de rejeux au fil de l'eau sans garder tout
+/// l'historique en mémoire.
+pub struct HandHistoryWriter<W: std::io::Write> {
+    writer: W,
+}
+
+impl<W: std::io::Write> HandHistoryWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Ajoute `replay` comme nouvelle ligne JSON du journal.
+    pub fn append(&mut self, replay: &HandReplay) -> Result<(), Box<dyn std::error::Error>> {
+        serde_json::to_writer(&mut self.writer, replay)?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+impl FullCfrEngine {
+    /// Normalise `strategy_sum[info_set]` en distribution de probabilités
+    /// (stratégie moyenne convergée), comme `Strategy::get_average_strategy`.
+    fn average_strategy_for(actions: &HashMap<Action, f64>) -> HashMap<Action, f64> {
+        let total: f64 = actions.values().sum();
+        if total <= 0.0 {
+            return HashMap::new();
+        }
+        actions.iter().map(|(action, sum)| (action.clone(), sum / total)).collect()
+    }
+
+    /// Construit le pas de rejeu de `state`: actions légales, clé
+    /// d'information set et stratégie moyenne convergée à ce point.
+    pub fn replay_step(&self, state: &PokerState) -> HandReplayStep {
+        let legal_actions = Self::get_legal_actions_fast(state);
+        let info_set = Self::get_information_set_fast(state);
+
+        let average_strategy = self
+            .strategy_sum
+            .lock()
+            .ok()
+            .and_then(|strategy_sum| strategy_sum.get(&info_set).map(Self::average_strategy_for))
+            .unwrap_or_default();
+
+        HandReplayStep {
+            state: state.clone(),
+            legal_actions,
+            info_set,
+            average_strategy,
+        }
+    }
+
+    /// Exporte la stratégie moyenne convergée de tous les information sets
+    /// visités (`info_set -> {action -> probabilité moyenne}`) en JSON
+    /// lisible, pour inspection ou analyse externe outil-agnostique.
+    pub fn export_strategy_json<W: std::io::Write>(&self, writer: W) -> Result<(), Box<dyn std::error::Error>> {
+        let strategy_sum = self.strategy_sum.lock().map(|g| g.clone()).unwrap_or_default();
+
+        let average: BTreeMap<String, HashMap<Action, f64>> = strategy_sum
+            .iter()
+            .map(|(info_set, actions)| (info_set.clone(), Self::average_strategy_for(actions)))
+            .collect();
+
+        serde_json::to_writer_pretty(writer, &average)?;
+        Ok(())
+    }
 }
 
 /// 🚀 TRAINER CFR HAUTE PERFORMANCE
@@ -408,6 +1411,25 @@ pub struct FullCfrTrainer {
     pub engine: Arc<Mutex<FullCfrEngine>>,
     pub iterations_completed: std::sync::atomic::AtomicUsize,
     pub is_training: std::sync::atomic::AtomicBool,
+    /// Politique d'auto-checkpoint (désactivée si `None`)
+    pub checkpoint_policy: Option<CheckpointPolicy>,
+    /// Id du dernier snapshot gelé, utilisé comme `parent_id` du prochain
+    last_snapshot_id: Mutex<Option<u64>>,
+    /// Fichiers de snapshot déjà écrits, du plus ancien au plus récent, pour
+    /// purger au-delà de `CheckpointPolicy::keep_last`
+    checkpoint_history: Mutex<VecDeque<PathBuf>>,
+}
+
+/// Fréquence et rétention de l'auto-checkpoint de `FullCfrTrainer`: gèle un
+/// `CfrSnapshot` tous les `every_n_iterations` et ne garde que les
+/// `keep_last` fichiers les plus récents dans `directory`, pour permettre
+/// de pause/reprendre/bifurquer un entraînement long sans épuiser le disque.
+#[derive(Debug, Clone)]
+pub struct CheckpointPolicy {
+    /// 0 désactive l'auto-checkpoint
+    pub every_n_iterations: usize,
+    pub keep_last: usize,
+    pub directory: PathBuf,
 }
 
 impl FullCfrTrainer {
@@ -416,31 +1438,143 @@ impl FullCfrTrainer {
             engine: Arc::new(Mutex::new(engine)),
             iterations_completed: std::sync::atomic::AtomicUsize::new(0),
             is_training: std::sync::atomic::AtomicBool::new(false),
+            checkpoint_policy: None,
+            last_snapshot_id: Mutex::new(None),
+            checkpoint_history: Mutex::new(VecDeque::new()),
         }
     }
 
+    /// Active l'auto-checkpoint sur ce trainer selon `policy`.
+    pub fn with_checkpoint_policy(mut self, policy: CheckpointPolicy) -> Self {
+        self.checkpoint_policy = Some(policy);
+        self
+    }
+
+    /// Gèle l'état courant de l'engine sur disque si `policy` le demande
+    /// (un multiple de `every_n_iterations` a été franchi depuis
+    /// `previous_iterations`), en chaînant le nouveau snapshot au précédent
+    /// de cette lignée et en purgeant les checkpoints excédentaires.
+    fn maybe_checkpoint(&self, previous_iterations: usize, total_iterations: usize) {
+        let policy = match &self.checkpoint_policy {
+            Some(policy) if policy.every_n_iterations > 0 => policy,
+            _ => return,
+        };
+
+        if total_iterations / policy.every_n_iterations <= previous_iterations / policy.every_n_iterations {
+            return;
+        }
+
+        if let Err(e) = self.checkpoint_now(policy, total_iterations) {
+            eprintln!("⚠️ Échec de l'auto-checkpoint: {e}");
+        }
+    }
+
+    fn checkpoint_now(&self, policy: &CheckpointPolicy, iterations_completed: usize) -> Result<(), Box<dyn std::error::Error>> {
+        let parent_id = self.last_snapshot_id.lock().map(|g| *g).unwrap_or(None);
+
+        let snapshot = {
+            let engine = self.engine.lock().map_err(|_| "mutex de l'engine empoisonné")?;
+            engine.freeze(parent_id, iterations_completed)
+        };
+
+        std::fs::create_dir_all(&policy.directory)?;
+        let path = policy.directory.join(format!("{:016x}.json", snapshot.id));
+        FullCfrEngine::save_snapshot(&snapshot, &path)?;
+
+        if let Ok(mut last_id) = self.last_snapshot_id.lock() {
+            *last_id = Some(snapshot.id);
+        }
+
+        if let Ok(mut history) = self.checkpoint_history.lock() {
+            history.push_back(path);
+            while history.len() > policy.keep_last.max(1) {
+                if let Some(oldest) = history.pop_front() {
+                    let _ = std::fs::remove_file(oldest);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// 🔥 TRAINING INTENSIF HAUTE PERFORMANCE
-    pub fn train_intensive_batch(&self, states: &[PokerState], 
+    ///
+    /// Construit un arbre CFR par état racine (réutilisé sur toutes les
+    /// itérations de ce batch) et parcourt `FullCfrEngine::cfr` dessus
+    /// `max_iterations` fois: les regrets/stratégies accumulés convergent
+    /// vers une stratégie de Nash au lieu des "regrets" à un coup de
+    /// l'ancienne heuristique. La convergence retournée est la moyenne des
+    /// variations absolues de la valeur racine d'une itération à l'autre.
+    pub fn train_intensive_batch(&self, states: &[PokerState],
         max_iterations: usize) -> f64 {
-        
+
         self.is_training.store(true, std::sync::atomic::Ordering::Relaxed);
-        
-        let convergence: f64 = (0..max_iterations)
-            .into_par_iter()  // Parallélisation des itérations
-            .map(|_iteration| {
-                if let Ok(mut engine) = self.engine.lock() {
-                    engine.update_cfr_tables_batch(states)
-                } else {
-                    0.0
+
+        // Itération globale de départ: les regrets/stratégie continuent à se
+        // décoter/moyenner comme si ce batch poursuivait l'entraînement
+        // précédent plutôt que de repartir de l'itération 1 à chaque appel.
+        let base_iteration = self.iterations_completed.load(std::sync::atomic::Ordering::Relaxed);
+
+        if let Ok(engine) = self.engine.lock() {
+            engine.reset_touched_info_sets();
+        }
+
+        let convergence: f64 = states
+            .par_iter()
+            .map(|state| {
+                let mut tree = CfrTree::new();
+                let root = tree.build(state);
+
+                // Un seul verrou par itération (pas un verrou tenu sur tout
+                // le batch `max_iterations`): chaque état du `par_iter()`
+                // relâche le mutex partagé entre deux itérations, sinon le
+                // parallélisme entre états serait purement cosmétique — tous
+                // les autres états attendraient que le premier termine ses
+                // `max_iterations` avant de pouvoir ne serait-ce que démarrer.
+                let run_iteration = |iteration: usize| -> f64 {
+                    let engine = match self.engine.lock() {
+                        Ok(engine) => engine,
+                        Err(_) => return 0.0,
+                    };
+                    // `sampling_enabled` bascule entre un parcours complet de
+                    // l'arbre (vanille/CFR+/Discounted sur tous les noeuds) et un
+                    // mode Monte-Carlo qui n'échantillonne qu'une trajectoire par
+                    // itération, selon `config.sampling`.
+                    if !engine.config.sampling_enabled {
+                        return engine.cfr(&tree, root, [1.0, 1.0], iteration);
+                    }
+                    match engine.config.sampling {
+                        SamplingMode::FullTree => engine.cfr(&tree, root, [1.0, 1.0], iteration),
+                        SamplingMode::OutcomeSampling => {
+                            engine.cfr_outcome_sampling(&tree, root, iteration)
+                        }
+                        SamplingMode::ExternalSampling => {
+                            // Alterne le traverseur à chaque itération, comme
+                            // `CfrEngine::train_batch_cpu`.
+                            let traverser = (iteration % 2) as u8;
+                            engine.cfr_external_sampling(&tree, root, [1.0, 1.0], traverser, iteration)
+                        }
+                    }
+                };
+
+                let mut previous_value = run_iteration(base_iteration + 1);
+                let mut delta_sum = 0.0;
+                for i in 1..max_iterations.max(1) {
+                    let value = run_iteration(base_iteration + i + 1);
+                    delta_sum += (value - previous_value).abs();
+                    previous_value = value;
                 }
+
+                delta_sum / (max_iterations.max(2) - 1) as f64
             })
-            .sum::<f64>() / max_iterations as f64;
-        
-        self.iterations_completed.fetch_add(max_iterations, 
+            .sum::<f64>() / states.len().max(1) as f64;
+
+        let previous_iterations = self.iterations_completed.fetch_add(max_iterations,
             std::sync::atomic::Ordering::Relaxed);
-        
+        self.maybe_checkpoint(previous_iterations, previous_iterations + max_iterations);
+
         self.is_training.store(false, std::sync::atomic::Ordering::Relaxed);
-        
+
         convergence
     }
 }
\ No newline at end of file
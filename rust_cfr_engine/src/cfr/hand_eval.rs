@@ -0,0 +1,191 @@
+/// Évaluateur de mains de poker (7 cartes -> meilleure main 5 cartes)
+use crate::types::Card;
+use std::collections::HashMap;
+
+/// Catégories de mains, de la plus faible (0) à la plus forte (8)
+const HIGH_CARD: u8 = 0;
+const ONE_PAIR: u8 = 1;
+const TWO_PAIR: u8 = 2;
+const TRIPS: u8 = 3;
+const STRAIGHT: u8 = 4;
+const FLUSH: u8 = 5;
+const FULL_HOUSE: u8 = 6;
+const QUADS: u8 = 7;
+const STRAIGHT_FLUSH: u8 = 8;
+
+/// Clé de comparaison totalement ordonnée pour une main de 5 cartes:
+/// (catégorie, tiebreaks triés par importance décroissante)
+pub type HandRank = (u8, [u8; 5]);
+
+/// Évalue la meilleure main 5 cartes parmi les 5..=7 cartes fournies
+/// (hole cards + community cards) et retourne sa clé de force.
+pub fn evaluate_hand(cards: &[Card]) -> HandRank {
+    debug_assert!(cards.len() >= 5 && cards.len() <= 7);
+
+    let mut best: HandRank = (HIGH_CARD, [0; 5]);
+    for combo in combinations_5(cards) {
+        let key = rank_five(&combo);
+        if key > best {
+            best = key;
+        }
+    }
+    best
+}
+
+/// Variante packée en `u32` de `evaluate_hand`: catégorie sur les bits
+/// 20-23 et jusqu'à cinq rangs départageurs sur 4 bits chacun (bits 0-19),
+/// pour un ordre total comparable par simple `>` sur l'entier — pratique
+/// quand l'appelant veut une clé scalaire plutôt que le tuple `HandRank`
+/// (ex: comparaison directe de mains de plusieurs joueurs en boucle chaude).
+pub fn evaluate_hand_rank(cards: &[Card]) -> u32 {
+    let (category, tiebreak) = evaluate_hand(cards);
+    let tiebreak_bits = tiebreak.iter().fold(0u32, |acc, &rank| (acc << 4) | rank as u32);
+    ((category as u32) << 20) | tiebreak_bits
+}
+
+/// Génère toutes les combinaisons de 5 cartes parmi `cards` (C(n,5))
+fn combinations_5(cards: &[Card]) -> Vec<[Card; 5]> {
+    let n = cards.len();
+    let mut out = Vec::with_capacity(21);
+    let mut idx = [0usize; 5];
+    for a in 0..n {
+        idx[0] = a;
+        for b in (a + 1)..n {
+            idx[1] = b;
+            for c in (b + 1)..n {
+                idx[2] = c;
+                for d in (c + 1)..n {
+                    idx[3] = d;
+                    for e in (d + 1)..n {
+                        idx[4] = e;
+                        out.push([cards[a], cards[b], cards[c], cards[d], cards[e]]);
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Classe une main exacte de 5 cartes
+fn rank_five(hand: &[Card; 5]) -> HandRank {
+    let is_flush = hand.iter().all(|c| c.suit == hand[0].suit);
+
+    let mut ranks: Vec<u8> = hand.iter().map(|c| c.rank).collect();
+    ranks.sort_unstable_by(|a, b| b.cmp(a)); // décroissant
+
+    let straight_high = straight_high_card(&ranks);
+
+    if is_flush {
+        if let Some(high) = straight_high {
+            return (STRAIGHT_FLUSH, [high, 0, 0, 0, 0]);
+        }
+    }
+
+    let mut counts: HashMap<u8, u8> = HashMap::new();
+    for &r in &ranks {
+        *counts.entry(r).or_insert(0) += 1;
+    }
+
+    // Groupes triés par (nombre d'occurrences desc, rang desc)
+    let mut groups: Vec<(u8, u8)> = counts.into_iter().map(|(rank, count)| (count, rank)).collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+
+    let tiebreak = |groups: &[(u8, u8)]| -> [u8; 5] {
+        let mut out = [0u8; 5];
+        for (i, &(_, rank)) in groups.iter().enumerate().take(5) {
+            out[i] = rank;
+        }
+        out
+    };
+
+    match groups[0].0 {
+        4 => (QUADS, tiebreak(&groups)),
+        3 if groups.len() > 1 && groups[1].0 >= 2 => (FULL_HOUSE, tiebreak(&groups)),
+        _ if is_flush => (FLUSH, tiebreak(&groups)),
+        _ if straight_high.is_some() => (STRAIGHT, [straight_high.unwrap(), 0, 0, 0, 0]),
+        3 => (TRIPS, tiebreak(&groups)),
+        2 if groups.len() > 1 && groups[1].0 == 2 => (TWO_PAIR, tiebreak(&groups)),
+        2 => (ONE_PAIR, tiebreak(&groups)),
+        _ => (HIGH_CARD, tiebreak(&groups)),
+    }
+}
+
+/// Retourne le rang haut de la quinte si les 5 rangs (triés, sans doublon)
+/// forment une suite, en gérant le cas spécial de la "roue" A-2-3-4-5
+/// (l'as y vaut 1, donc la quinte haute est le 5).
+fn straight_high_card(sorted_desc: &[u8]) -> Option<u8> {
+    let mut unique: Vec<u8> = sorted_desc.to_vec();
+    unique.dedup();
+    if unique.len() != 5 {
+        return None;
+    }
+
+    if unique[0] - unique[4] == 4 {
+        return Some(unique[0]);
+    }
+
+    // Roue: A, 5, 4, 3, 2
+    if unique == [14, 5, 4, 3, 2] {
+        return Some(5);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(rank: u8, suit: u8) -> Card {
+        Card { rank, suit }
+    }
+
+    #[test]
+    fn wheel_straight_beats_no_straight() {
+        let wheel = [card(14, 0), card(2, 1), card(3, 2), card(4, 3), card(5, 0)];
+        let (category, tiebreak) = rank_five(&wheel);
+        assert_eq!(category, STRAIGHT);
+        assert_eq!(tiebreak[0], 5); // la quinte haute est le 5, pas l'as
+        let no_straight = [card(14, 0), card(2, 1), card(3, 2), card(4, 3), card(6, 0)];
+        assert!(rank_five(&wheel) > rank_five(&no_straight));
+    }
+
+    #[test]
+    fn flush_beats_straight() {
+        let flush = [card(2, 0), card(5, 0), card(9, 0), card(11, 0), card(13, 0)];
+        let straight = [card(4, 0), card(5, 1), card(6, 2), card(7, 3), card(8, 0)];
+        assert!(rank_five(&flush) > rank_five(&straight));
+    }
+
+    #[test]
+    fn kicker_breaks_pair_tie() {
+        let pair_ace_kicker = [card(9, 0), card(9, 1), card(14, 2), card(7, 3), card(4, 0)];
+        let pair_king_kicker = [card(9, 2), card(9, 3), card(13, 0), card(7, 1), card(4, 2)];
+        assert!(rank_five(&pair_ace_kicker) > rank_five(&pair_king_kicker));
+    }
+
+    #[test]
+    fn evaluate_hand_rank_orders_like_evaluate_hand() {
+        let flush = [card(2, 0), card(5, 0), card(9, 0), card(11, 0), card(13, 0)];
+        let straight = [card(4, 0), card(5, 1), card(6, 2), card(7, 3), card(8, 0)];
+        assert!(evaluate_hand_rank(&flush) > evaluate_hand_rank(&straight));
+        assert!(evaluate_hand(&flush) > evaluate_hand(&straight));
+    }
+
+    #[test]
+    fn best_of_seven_picks_the_winning_five() {
+        // Main de 7 cartes contenant un full house caché parmi du bruit
+        let seven = [
+            card(6, 0),
+            card(6, 1),
+            card(6, 2),
+            card(9, 0),
+            card(9, 1),
+            card(2, 3),
+            card(4, 2),
+        ];
+        let (category, _) = evaluate_hand(&seven);
+        assert_eq!(category, FULL_HOUSE);
+    }
+}
@@ -2,7 +2,10 @@
 pub mod engine;
 pub mod trainer;
 pub mod abstraction;
+pub mod hand_eval;
+pub mod full_engine;
 
 pub use engine::CfrEngine;
 pub use trainer::CfrTrainer;
-pub use abstraction::{CardAbstraction, AbstractionManager};
\ No newline at end of file
+pub use abstraction::{CardAbstraction, AbstractionManager};
+pub use full_engine::{FullCfrEngine, FullCfrTrainer};
\ No newline at end of file
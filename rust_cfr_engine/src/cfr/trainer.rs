@@ -2,29 +2,198 @@
 use crate::types::*;
 use crate::cfr::CfrEngine;
 use rayon::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, atomic::{AtomicUsize, AtomicBool, Ordering}};
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
+/// Un enregistrement de progression, sérialisé en JSON (une ligne par
+/// intervalle de log) par `start_training` sur `progress_writer` pour
+/// permettre à un harness externe de suivre l'entraînement sans parser du
+/// texte à emoji.
+#[derive(Debug, Clone, Serialize)]
+struct ProgressRecord {
+    iteration: usize,
+    convergence: f64,
+    avg_convergence: f64,
+    exploitability: f64,
+    iter_per_sec: f64,
+    elapsed_secs: f64,
+    sampling: PhaseStats,
+    train_batch: PhaseStats,
+    update: PhaseStats,
+    rolling_iter_per_sec: f64,
+    peak_iter_per_sec: f64,
+    degradation_detected: bool,
+}
+
+/// Nombre d'itérations sur lesquelles les moyennes glissantes par phase et
+/// le débit récent sont calculés (même fenêtre que `get_average_convergence`).
+const TELEMETRY_WINDOW: usize = 100;
+
+/// En-dessous de cette fraction du débit crête observé, le débit récent est
+/// considéré comme une dégradation plutôt qu'un simple bruit de mesure.
+const DEGRADATION_THRESHOLD_FRACTION: f64 = 0.5;
+
+/// Moyenne "tout le run" vs moyenne "fenêtre récente" d'une phase, le même
+/// motif que `get_average_convergence` applique déjà à la convergence.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct PhaseStats {
+    pub all_run_avg_secs: f64,
+    pub recent_avg_secs: f64,
+}
+
+/// Historique des durées d'une phase d'itération (échantillonnage, passage
+/// CFR, mise à jour des métriques): un total/compte cumulé depuis le début
+/// du run, et une fenêtre glissante des `TELEMETRY_WINDOW` dernières mesures
+/// pour détecter une dérive récente sans attendre la moyenne de tout le run.
+#[derive(Debug, Clone, Default)]
+struct PhaseHistory {
+    all_run_total: Duration,
+    all_run_count: usize,
+    recent: std::collections::VecDeque<Duration>,
+}
+
+impl PhaseHistory {
+    fn record(&mut self, elapsed: Duration) {
+        self.all_run_total += elapsed;
+        self.all_run_count += 1;
+        self.recent.push_back(elapsed);
+        if self.recent.len() > TELEMETRY_WINDOW {
+            self.recent.pop_front();
+        }
+    }
+
+    fn recent_avg_secs(&self) -> f64 {
+        if self.recent.is_empty() {
+            return 0.0;
+        }
+        self.recent.iter().map(Duration::as_secs_f64).sum::<f64>() / self.recent.len() as f64
+    }
+
+    fn stats(&self) -> PhaseStats {
+        PhaseStats {
+            all_run_avg_secs: if self.all_run_count == 0 {
+                0.0
+            } else {
+                self.all_run_total.as_secs_f64() / self.all_run_count as f64
+            },
+            recent_avg_secs: self.recent_avg_secs(),
+        }
+    }
+}
+
+/// Télémétrie par phase d'itération: identifie *laquelle* de
+/// l'échantillonnage, du passage CFR (`train_batch`) ou de la mise à jour
+/// des métriques ralentit, plutôt que de se contenter d'un débit global qui
+/// s'effondre en fin de run sans dire pourquoi (contention sur les tables de
+/// stratégie partagées, pression cache/mémoire, ...).
+#[derive(Debug, Clone, Default)]
+struct PhaseTelemetry {
+    sampling: PhaseHistory,
+    train_batch: PhaseHistory,
+    update: PhaseHistory,
+    iteration: PhaseHistory,
+    peak_iter_per_sec: f64,
+}
+
+impl PhaseTelemetry {
+    fn record_iteration(&mut self, sampling: Duration, train_batch: Duration, update: Duration, total: Duration) {
+        self.sampling.record(sampling);
+        self.train_batch.record(train_batch);
+        self.update.record(update);
+        self.iteration.record(total);
+
+        let instantaneous_rate = if total.as_secs_f64() > 0.0 { 1.0 / total.as_secs_f64() } else { 0.0 };
+        if instantaneous_rate > self.peak_iter_per_sec {
+            self.peak_iter_per_sec = instantaneous_rate;
+        }
+    }
+
+    fn rolling_iter_per_sec(&self) -> f64 {
+        let recent_avg = self.iteration.recent_avg_secs();
+        if recent_avg > 0.0 { 1.0 / recent_avg } else { 0.0 }
+    }
+
+    fn degradation_detected(&self) -> bool {
+        self.peak_iter_per_sec > 0.0
+            && self.rolling_iter_per_sec() < self.peak_iter_per_sec * DEGRADATION_THRESHOLD_FRACTION
+    }
+}
+
 pub struct CfrTrainer {
     engine: Arc<CfrEngine>,
     config: CfrConfig,
     is_training: AtomicBool,
     iterations_completed: AtomicUsize,
     training_start_time: Arc<std::sync::Mutex<Option<Instant>>>,
+    /// Flux déterministe consommé séquentiellement par `sample_training_batch`
+    /// (voir `CfrConfig::seed`): avec une graine fixée, deux runs produisent
+    /// des batches byte-identiques itération par itération, ce qui rend
+    /// possible des tests de régression sur la trace de convergence.
+    rng: std::sync::Mutex<ChaCha20Rng>,
+    /// Flux JSON de progression optionnel (voir `with_progress_writer` et
+    /// `ProgressRecord`). `None` conserve le comportement `println!` existant.
+    progress_writer: Option<std::sync::Mutex<Box<dyn Write + Send>>>,
+    /// Chemin + cadence des checkpoints périodiques (voir `with_checkpoint`):
+    /// sérialise `CfrEngine::export_data` (stratégies + itérations) pour que
+    /// `resume_from` puisse reprendre un run interrompu.
+    checkpoint: Option<(PathBuf, usize)>,
+    /// Télémétrie par phase d'itération (voir `PhaseTelemetry`), utilisée
+    /// pour diagnostiquer un ralentissement en fin de run.
+    phase_telemetry: std::sync::Mutex<PhaseTelemetry>,
 }
 
 impl CfrTrainer {
     pub fn new(engine: Arc<CfrEngine>, config: CfrConfig) -> Self {
+        let seed = config.seed.unwrap_or_else(|| rand::thread_rng().gen());
         Self {
             engine,
+            rng: std::sync::Mutex::new(ChaCha20Rng::seed_from_u64(seed)),
             config,
             is_training: AtomicBool::new(false),
             iterations_completed: AtomicUsize::new(0),
             training_start_time: Arc::new(std::sync::Mutex::new(None)),
+            progress_writer: None,
+            checkpoint: None,
+            phase_telemetry: std::sync::Mutex::new(PhaseTelemetry::default()),
         }
     }
 
+    /// Reprend un entraînement interrompu: recharge dans `engine` le
+    /// snapshot JSON écrit à `path` par un checkpoint périodique (voir
+    /// `with_checkpoint`), puis construit un trainer dont
+    /// `iterations_completed` reflète l'itération restaurée au lieu de
+    /// repartir de zéro.
+    pub fn resume_from(engine: Arc<CfrEngine>, config: CfrConfig, path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = std::fs::read_to_string(path)?;
+        engine.import_data(&data)?;
+        let (restored_iterations, _) = engine.get_convergence_stats();
+
+        let trainer = Self::new(engine, config);
+        trainer.iterations_completed.store(restored_iterations, Ordering::Relaxed);
+        Ok(trainer)
+    }
+
+    /// Flux (fichier, socket, ...) sur lequel écrire un `ProgressRecord` JSON
+    /// par intervalle de log, en plus des `println!` existants.
+    pub fn with_progress_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.progress_writer = Some(std::sync::Mutex::new(Box::new(writer)));
+        self
+    }
+
+    /// Active des checkpoints périodiques: tous les `interval` itérations,
+    /// `CfrEngine::export_data` est écrit sur `path`, rechargeable par
+    /// `resume_from`.
+    pub fn with_checkpoint(mut self, path: PathBuf, interval: usize) -> Self {
+        self.checkpoint = Some((path, interval.max(1)));
+        self
+    }
+
     /// Démarrer entraînement asynchrone  
     pub async fn start_training(&self, states: Vec<PokerState>) -> Result<(), String> {
         if self.is_training.load(Ordering::Relaxed) {
@@ -32,8 +201,7 @@ impl CfrTrainer {
         }
 
         self.is_training.store(true, Ordering::Relaxed);
-        self.iterations_completed.store(0, Ordering::Relaxed);
-        
+
         // Enregistrer heure de début
         if let Ok(mut start_time) = self.training_start_time.lock() {
             *start_time = Some(Instant::now());
@@ -42,27 +210,65 @@ impl CfrTrainer {
         println!("🚀 Démarrage CFR training Rust:");
         println!("   • States: {}", states.len());
         println!("   • Max iterations: {}", self.config.max_iterations);
-        println!("   • GPU: {}", self.engine.gpu_compute.is_some());
         println!("   • CPU threads: {}", self.config.cpu_threads);
+        if let Some(budget) = self.config.time_budget {
+            println!("   • Budget de temps (anytime): {:.1}s", budget.as_secs_f64());
+        }
 
-        // Boucle d'entraînement principale
-        let mut iteration = 0;
+        // Boucle d'entraînement principale: reprend à `iterations_completed`
+        // plutôt que de forcer 0, pour que `resume_from` continue un run
+        // interrompu au lieu de recompter depuis zéro.
+        let mut iteration = self.iterations_completed.load(Ordering::Relaxed);
         let mut best_convergence = f64::INFINITY;
         let convergence_history = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let training_start = Instant::now();
+        let mut time_budget_exhausted = false;
 
-        while self.is_training.load(Ordering::Relaxed) && iteration < self.config.max_iterations {
+        while self.is_training.load(Ordering::Relaxed)
+            && iteration < self.config.max_iterations
+            && !time_budget_exhausted
+        {
             let iter_start = Instant::now();
-            
+
+            // Progression du run en cours, sur l'échelle pertinente: le
+            // budget de temps s'il est fixé (entraînement "anytime"), sinon
+            // la fraction d'itérations écoulées. Pilote le recuit du taux
+            // d'exploration ε ci-dessous (voir `CfrConfig::exploration_rate`
+            // /`exploration_cooling_rate` et `CfrEngine::sample_action`).
+            let progress = match self.config.time_budget {
+                Some(budget) => (training_start.elapsed().as_secs_f64() / budget.as_secs_f64().max(f64::EPSILON)).min(1.0),
+                None => (iteration as f64 / self.config.max_iterations.max(1) as f64).min(1.0),
+            };
+            let floor = crate::cfr::engine::EXPLORATION_RATE_FLOOR;
+            let annealed_rate = floor
+                + (self.config.exploration_rate - floor).max(0.0)
+                    * self.config.exploration_cooling_rate.powf(progress);
+            if let Ok(mut rate) = self.engine.current_exploration_rate.lock() {
+                *rate = annealed_rate;
+            }
+
+            if let Some(budget) = self.config.time_budget {
+                if training_start.elapsed() >= budget {
+                    time_budget_exhausted = true;
+                    break;
+                }
+            }
+
             // Échantillonner batch pour cette itération
+            let sampling_start = Instant::now();
             let batch = self.sample_training_batch(&states, 100).map_err(|e| e.to_string())?;
-            
+            let sampling_elapsed = sampling_start.elapsed();
+
             // Entraînement CFR sur batch
-            let convergence = self.engine.train_batch(&batch).await.map_err(|e| e.to_string())?;
-            
+            let train_batch_start = Instant::now();
+            let convergence = self.engine.train_batch(&batch).await;
+            let train_batch_elapsed = train_batch_start.elapsed();
+
             // Mise à jour métriques
+            let update_start = Instant::now();
             self.iterations_completed.fetch_add(1, Ordering::Relaxed);
             iteration += 1;
-            
+
             // Suivi convergence
             if let Ok(mut history) = convergence_history.lock() {
                 history.push(convergence);
@@ -70,26 +276,103 @@ impl CfrTrainer {
                     history.remove(0); // Garder historique limité
                 }
             }
-            
+
             if convergence < best_convergence {
                 best_convergence = convergence;
             }
-            
+            let update_elapsed = update_start.elapsed();
+
+            if let Ok(mut telemetry) = self.phase_telemetry.lock() {
+                telemetry.record_iteration(sampling_elapsed, train_batch_elapsed, update_elapsed, iter_start.elapsed());
+            }
+
             // Log progression
             if iteration % 1000 == 0 {
                 let iter_time = iter_start.elapsed();
                 let avg_convergence = self.get_average_convergence(&convergence_history, 100);
-                
-                println!("🔄 Iteration {}: convergence={:.4f}, avg={:.4f}, temps={:.2}ms", 
-                    iteration, convergence, avg_convergence, iter_time.as_secs_f64() * 1000.0);
-                
-                // Vérifier convergence
-                if avg_convergence < self.config.convergence_threshold {
-                    println!("✅ Convergence atteinte à l'itération {}", iteration);
+
+                // `avg_convergence` n'est qu'un lissage du scalaire bruité
+                // retourné par `train_batch`; le vrai critère d'arrêt est
+                // l'exploitabilité (`CfrEngine::compute_exploitability`): la
+                // moyenne, sur les deux joueurs, de ce qu'un best-response
+                // exact extrait contre la stratégie moyenne courante de
+                // l'autre — 0 à l'équilibre de Nash, garantie
+                // game-théorique plutôt qu'une simple stabilisation de bruit.
+                let exploitability = self.engine.compute_exploitability(&states);
+                self.engine.refresh_convergence(&states);
+
+                println!("🔄 Iteration {}: convergence={:.4}, avg={:.4}, exploitabilité={:.4}, temps={:.2}ms",
+                    iteration, convergence, avg_convergence, exploitability, iter_time.as_secs_f64() * 1000.0);
+
+                let (sampling_stats, train_batch_stats, update_stats, rolling_iter_per_sec, peak_iter_per_sec, degradation_detected) =
+                    match self.phase_telemetry.lock() {
+                        Ok(telemetry) => (
+                            telemetry.sampling.stats(),
+                            telemetry.train_batch.stats(),
+                            telemetry.update.stats(),
+                            telemetry.rolling_iter_per_sec(),
+                            telemetry.peak_iter_per_sec,
+                            telemetry.degradation_detected(),
+                        ),
+                        Err(_) => Default::default(),
+                    };
+
+                if degradation_detected {
+                    println!(
+                        "🐢 Dégradation détectée: débit récent {:.1} iter/s < {:.0}% du débit crête {:.1} iter/s (sampling={:.2}ms, train_batch={:.2}ms, update={:.2}ms récents)",
+                        rolling_iter_per_sec,
+                        DEGRADATION_THRESHOLD_FRACTION * 100.0,
+                        peak_iter_per_sec,
+                        sampling_stats.recent_avg_secs * 1000.0,
+                        train_batch_stats.recent_avg_secs * 1000.0,
+                        update_stats.recent_avg_secs * 1000.0,
+                    );
+                }
+
+                if let Some(writer) = &self.progress_writer {
+                    let elapsed_secs = self.training_start_time.lock().ok()
+                        .and_then(|t| t.as_ref().map(|t| t.elapsed().as_secs_f64()))
+                        .unwrap_or(0.0);
+                    let record = ProgressRecord {
+                        iteration,
+                        convergence,
+                        avg_convergence,
+                        exploitability,
+                        iter_per_sec: if iter_time.as_secs_f64() > 0.0 { 1.0 / iter_time.as_secs_f64() } else { 0.0 },
+                        elapsed_secs,
+                        sampling: sampling_stats,
+                        train_batch: train_batch_stats,
+                        update: update_stats,
+                        rolling_iter_per_sec,
+                        peak_iter_per_sec,
+                        degradation_detected,
+                    };
+                    if let Ok(mut writer) = writer.lock() {
+                        if serde_json::to_writer(&mut *writer, &record).is_ok() {
+                            let _ = writer.write_all(b"\n");
+                        }
+                    }
+                }
+
+                if let Some((path, interval)) = &self.checkpoint {
+                    if iteration % interval == 0 {
+                        match self.engine.export_data() {
+                            Ok(data) => {
+                                if let Err(e) = std::fs::write(path, data) {
+                                    println!("⚠️  Échec du checkpoint vers {}: {}", path.display(), e);
+                                }
+                            }
+                            Err(e) => println!("⚠️  Échec de l'export pour checkpoint: {}", e),
+                        }
+                    }
+                }
+
+                if exploitability < self.config.convergence_threshold {
+                    println!("✅ Convergence (exploitabilité) atteinte à l'itération {}", iteration);
                     break;
                 }
             }
-            
+
             // Pause courte pour éviter overload
             if iteration % 10 == 0 {
                 sleep(Duration::from_millis(1)).await;
@@ -97,17 +380,36 @@ impl CfrTrainer {
         }
 
         self.is_training.store(false, Ordering::Relaxed);
-        
+
         let total_time = if let Ok(start_time) = self.training_start_time.lock() {
             start_time.as_ref().map(|t| t.elapsed()).unwrap_or(Duration::ZERO)
         } else {
             Duration::ZERO
         };
 
+        if time_budget_exhausted {
+            // Arrêt "anytime": le budget de temps a expiré avant convergence
+            // ou max_iterations. On sauvegarde tout de même la meilleure
+            // stratégie trouvée à ce jour, via le même mécanisme de
+            // checkpoint que `self.checkpoint` (voir `with_checkpoint`), pour
+            // qu'un appelant pressé reparte de ce point avec `resume_from`.
+            println!("⏱️  Budget de temps épuisé à l'itération {} — arrêt anytime", iteration);
+            if let Some((path, _)) = &self.checkpoint {
+                match self.engine.export_data() {
+                    Ok(data) => {
+                        if let Err(e) = std::fs::write(path, data) {
+                            println!("⚠️  Échec du checkpoint final anytime vers {}: {}", path.display(), e);
+                        }
+                    }
+                    Err(e) => println!("⚠️  Échec de l'export pour checkpoint final anytime: {}", e),
+                }
+            }
+        }
+
         println!("🎯 Training terminé:");
         println!("   • Itérations: {}", iteration);
         println!("   • Temps total: {:.2}s", total_time.as_secs_f64());
-        println!("   • Convergence finale: {:.4f}", best_convergence);
+        println!("   • Convergence finale: {:.4}", best_convergence);
         println!("   • Débit: {:.1} iter/s", iteration as f64 / total_time.as_secs_f64());
 
         Ok(())
@@ -122,19 +424,32 @@ impl CfrTrainer {
     /// Échantillonner batch de training
     fn sample_training_batch(&self, states: &[PokerState], batch_size: usize) -> Result<Vec<PokerState>, String> {
         use rand::seq::SliceRandom;
-        use rand::thread_rng;
-        
-        let mut rng = thread_rng();
+
         let sample_size = batch_size.min(states.len());
-        
+        let mut rng = self.rng.lock().map_err(|e| e.to_string())?;
+
         let sampled: Vec<PokerState> = states
-            .choose_multiple(&mut rng, sample_size)
+            .choose_multiple(&mut *rng, sample_size)
             .cloned()
             .collect();
-            
+
         Ok(sampled)
     }
 
+    /// Dérive un flux `ChaCha20Rng` propre à un worker donné (`stream_tag`
+    /// identifie la catégorie parallèle - preflop/flop/turn/river/shuffle -
+    /// et `index` la tâche dans cette catégorie), par mélange FNV-like du
+    /// même genre que `CfrSnapshot::content_hash`. Pur fonction de
+    /// `(base_seed, stream_tag, index)`: l'ordre d'exécution des tâches
+    /// rayon n'influence jamais la graine qu'une tâche donnée reçoit, donc
+    /// `generate_training_hands` reste déterministe malgré `into_par_iter`.
+    fn worker_rng(base_seed: u64, stream_tag: u64, index: usize) -> ChaCha20Rng {
+        let mixed = base_seed
+            ^ stream_tag.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (index as u64).wrapping_mul(0xD1B5_4A32_D192_ED03);
+        ChaCha20Rng::seed_from_u64(mixed)
+    }
+
     /// Calculer convergence moyenne
     fn get_average_convergence(&self, history: &Arc<std::sync::Mutex<Vec<f64>>>, window: usize) -> f64 {
         if let Ok(history) = history.lock() {
@@ -181,7 +496,20 @@ impl CfrTrainer {
         };
 
         let (engine_iterations, convergence) = self.engine.get_convergence_stats();
-        
+
+        let (sampling, train_batch, update, rolling_iter_per_sec, peak_iter_per_sec, degradation_detected) =
+            match self.phase_telemetry.lock() {
+                Ok(telemetry) => (
+                    telemetry.sampling.stats(),
+                    telemetry.train_batch.stats(),
+                    telemetry.update.stats(),
+                    telemetry.rolling_iter_per_sec(),
+                    telemetry.peak_iter_per_sec,
+                    telemetry.degradation_detected(),
+                ),
+                Err(_) => Default::default(),
+            };
+
         TrainingStats {
             iterations,
             max_iterations: self.config.max_iterations,
@@ -190,88 +518,95 @@ impl CfrTrainer {
             estimated_time_remaining: eta,
             convergence_metric: convergence,
             engine_iterations,
+            sampling,
+            train_batch,
+            update,
+            rolling_iter_per_sec,
+            peak_iter_per_sec,
+            degradation_detected,
         }
     }
 
     /// Générer nouvelles hands pour training
     pub fn generate_training_hands(&self, count: usize) -> Result<Vec<PokerState>, String> {
-        use rand::{thread_rng, Rng};
         use rand::seq::SliceRandom;
-        
-        let mut rng = thread_rng();
+
+        let base_seed = self.config.seed.unwrap_or_else(|| rand::thread_rng().gen());
         let mut hands = Vec::with_capacity(count);
-        
+
         // Distribution des rounds de mise
         let preflop_ratio = 0.4;
         let flop_ratio = 0.3;
         let turn_ratio = 0.2;
         let river_ratio = 0.1;
-        
+
         let preflop_count = (count as f64 * preflop_ratio) as usize;
         let flop_count = (count as f64 * flop_ratio) as usize;
         let turn_count = (count as f64 * turn_ratio) as usize;
         let river_count = count - preflop_count - flop_count - turn_count;
-        
-        // Génération parallèle
+
+        // Génération parallèle: chaque tâche dérive son propre flux via
+        // `worker_rng(base_seed, stream_tag, index)`, donc le résultat ne
+        // dépend jamais de l'ordre d'exécution des threads rayon.
         let hands_preflop: Vec<PokerState> = (0..preflop_count)
             .into_par_iter()
-            .map(|_| self.generate_random_state(BettingRound::Preflop))
+            .map(|i| self.generate_random_state(BettingRound::Preflop, &mut Self::worker_rng(base_seed, 0, i)))
             .collect();
-            
+
         let hands_flop: Vec<PokerState> = (0..flop_count)
             .into_par_iter()
-            .map(|_| self.generate_random_state(BettingRound::Flop))
+            .map(|i| self.generate_random_state(BettingRound::Flop, &mut Self::worker_rng(base_seed, 1, i)))
             .collect();
-            
+
         let hands_turn: Vec<PokerState> = (0..turn_count)
             .into_par_iter()
-            .map(|_| self.generate_random_state(BettingRound::Turn))
+            .map(|i| self.generate_random_state(BettingRound::Turn, &mut Self::worker_rng(base_seed, 2, i)))
             .collect();
-            
+
         let hands_river: Vec<PokerState> = (0..river_count)
             .into_par_iter()
-            .map(|_| self.generate_random_state(BettingRound::River))
+            .map(|i| self.generate_random_state(BettingRound::River, &mut Self::worker_rng(base_seed, 3, i)))
             .collect();
-        
+
         hands.extend(hands_preflop);
         hands.extend(hands_flop);
         hands.extend(hands_turn);
         hands.extend(hands_river);
-        
-        // Mélanger
-        hands.shuffle(&mut rng);
-        
+
+        // Mélanger (flux dédié, tag 4, pour ne pas retirer à l'une des
+        // catégories ci-dessus son propre échantillonnage).
+        let mut shuffle_rng = Self::worker_rng(base_seed, 4, 0);
+        hands.shuffle(&mut shuffle_rng);
+
         Ok(hands)
     }
 
-    /// Générer state aléatoire pour round donné
-    fn generate_random_state(&self, round: BettingRound) -> PokerState {
-        use rand::{thread_rng, Rng};
-        let mut rng = thread_rng();
-        
+    /// Générer state aléatoire pour round donné, à partir d'un flux fourni
+    /// par l'appelant (voir `worker_rng`) plutôt que de `thread_rng()`, pour
+    /// que la génération reste reproductible sous `into_par_iter`.
+    fn generate_random_state(&self, round: BettingRound, rng: &mut ChaCha20Rng) -> PokerState {
         // Générer cartes hole aléatoires
         let hole_cards = vec![
             Card { rank: rng.gen_range(2..=14), suit: rng.gen_range(0..4) },
             Card { rank: rng.gen_range(2..=14), suit: rng.gen_range(0..4) },
         ];
-        
-        // Générer cartes communautaires selon round
-        let community_cards = match round {
-            BettingRound::Preflop => vec![],
-            BettingRound::Flop => (0..3).map(|_| Card {
-                rank: rng.gen_range(2..=14),
-                suit: rng.gen_range(0..4)
-            }).collect(),
-            BettingRound::Turn => (0..4).map(|_| Card {
-                rank: rng.gen_range(2..=14),
-                suit: rng.gen_range(0..4)
-            }).collect(),
-            BettingRound::River => (0..5).map(|_| Card {
-                rank: rng.gen_range(2..=14),
-                suit: rng.gen_range(0..4)
-            }).collect(),
+
+        // Deck sans collision avec les hole cards, mélangé par le même flux
+        // que les hole cards ci-dessus, d'où sont tirées les cartes
+        // communautaires et d'où continuera le run-out via
+        // `advance_betting_round`.
+        let mut deck = Deck::new_with_rng(&hole_cards, rng);
+
+        let community_count = match round {
+            BettingRound::Preflop => 0,
+            BettingRound::Flop => 3,
+            BettingRound::Turn => 4,
+            BettingRound::River => 5,
         };
-        
+        let community_cards: Vec<Card> = (0..community_count)
+            .filter_map(|_| deck.deal())
+            .collect();
+
         // Paramètres aléatoires réalistes
         let stack_size = rng.gen_range(50.0..=200.0);
         let pot_size = rng.gen_range(5.0..=50.0);
@@ -299,18 +634,88 @@ impl CfrTrainer {
             num_players,
             betting_round: round,
             available_actions,
+            folded: false,
+            deck: Some(deck),
         }
     }
 }
 
 /// Statistiques d'entraînement
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TrainingStats {
     pub iterations: usize,
     pub max_iterations: usize,
     pub is_training: bool,
+    #[serde(serialize_with = "serialize_duration_secs")]
     pub elapsed_time: Duration,
+    #[serde(serialize_with = "serialize_duration_secs")]
     pub estimated_time_remaining: Duration,
     pub convergence_metric: f64,
     pub engine_iterations: usize,
+    /// Temps moyen (tout le run / fenêtre récente) passé à échantillonner le
+    /// batch de chaque itération.
+    pub sampling: PhaseStats,
+    /// Temps moyen passé dans `CfrEngine::train_batch` (parcours CFR +
+    /// mise à jour regrets/stratégie, fusionnés côté moteur).
+    pub train_batch: PhaseStats,
+    /// Temps moyen passé dans la comptabilité post-batch (convergence,
+    /// compteurs d'itération).
+    pub update: PhaseStats,
+    /// Débit glissant (1 / temps moyen récent d'itération complète).
+    pub rolling_iter_per_sec: f64,
+    /// Débit crête instantané observé depuis le début du run.
+    pub peak_iter_per_sec: f64,
+    /// Vrai si `rolling_iter_per_sec` est tombé sous
+    /// `DEGRADATION_THRESHOLD_FRACTION` du débit crête.
+    pub degradation_detected: bool,
+}
+
+/// `Duration` n'implémente pas `Serialize` en amont: on le réduit à des
+/// secondes fractionnaires, seule valeur dont un harness externe a besoin.
+fn serialize_duration_secs<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn trainer_with_seed(seed: u64) -> CfrTrainer {
+        let mut config = CfrConfig::default();
+        config.gpu_config.enabled = false;
+        config.seed = Some(seed);
+        let engine = Arc::new(CfrEngine::new(config.clone()).await.unwrap());
+        CfrTrainer::new(engine, config)
+    }
+
+    #[tokio::test]
+    async fn same_seed_produces_byte_identical_batches() {
+        let trainer_a = trainer_with_seed(1234).await;
+        let trainer_b = trainer_with_seed(1234).await;
+
+        let batch_a = trainer_a.generate_training_hands(20).unwrap();
+        let batch_b = trainer_b.generate_training_hands(20).unwrap();
+
+        assert_eq!(batch_a.len(), batch_b.len());
+        for (a, b) in batch_a.iter().zip(batch_b.iter()) {
+            assert_eq!(a.hole_cards, b.hole_cards);
+            assert_eq!(a.community_cards, b.community_cards);
+            assert_eq!(a.betting_round, b.betting_round);
+        }
+        assert_eq!(serde_json::to_vec(&batch_a).unwrap(), serde_json::to_vec(&batch_b).unwrap());
+    }
+
+    #[tokio::test]
+    async fn different_seeds_diverge() {
+        let trainer_a = trainer_with_seed(1).await;
+        let trainer_b = trainer_with_seed(2).await;
+
+        let batch_a = trainer_a.generate_training_hands(20).unwrap();
+        let batch_b = trainer_b.generate_training_hands(20).unwrap();
+
+        assert!(batch_a.iter().zip(batch_b.iter()).any(|(a, b)| a.hole_cards != b.hole_cards));
+    }
 }
\ No newline at end of file
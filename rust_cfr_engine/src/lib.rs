@@ -5,10 +5,13 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use rand::prelude::*;
 
+pub mod cfr;
 pub mod types;
 
+use cfr::hand_eval::evaluate_hand;
+use types::{Card, Deck};
+
 /// Engine CFR Rust Ultra-Performance - ZERO FALLBACK Python
 #[pyclass]
 pub struct RustCfrEngine {
@@ -20,6 +23,11 @@ pub struct RustCfrEngine {
     equity_cache: Arc<Mutex<HashMap<String, f64>>>,
     /// Configuration
     config: HashMap<String, f64>,
+    /// Dernière itération globale (`iterations`) à laquelle chaque
+    /// information set a reçu une mise à jour, pour décoter correctement les
+    /// regrets/stratégie en Discounted CFR même si un set n'est pas visité à
+    /// chaque itération.
+    last_touched: Arc<Mutex<HashMap<String, usize>>>,
     /// Statistiques
     total_simulations: std::sync::atomic::AtomicU64,
     iterations: std::sync::atomic::AtomicUsize,
@@ -43,18 +51,29 @@ impl RustCfrEngine {
         // Defaults si manquants
         config.entry("max_iterations".to_string()).or_insert(10000.0);
         config.entry("convergence_threshold".to_string()).or_insert(0.01);
-        
+        // Non-zero: MCCFR à échantillonnage externe (noeud de hasard résolu
+        // par peu de tirages Monte-Carlo, 200 par appel). Zéro: équivalent
+        // "vanilla" (équité moyennée sur davantage de tirages, 5000 par appel).
+        config.entry("mccfr_enabled".to_string()).or_insert(1.0);
+        // Règle de mise à jour des regrets/stratégie: 0=Vanilla, 1=CFR+,
+        // 2=Discounted CFR (voir `apply_regret_update`/`apply_strategy_update`).
+        config.entry("update_rule".to_string()).or_insert(0.0);
+        config.entry("discount_alpha".to_string()).or_insert(1.5);
+        config.entry("discount_beta".to_string()).or_insert(0.0);
+        config.entry("discount_gamma".to_string()).or_insert(2.0);
+
         println!("🚀 CFR Engine Rust 100% PERFORMANCE - PYTHON CFR ÉLIMINÉ");
         println!("   ⚡ Performance: Calculs ultra-rapides natifs");
         println!("   🔥 Monte Carlo: Simulations optimisées");
         println!("   💾 Mémoire: Zero-copy, pas de GC Python");
         println!("   ❌ Fallback: AUCUN - Performance garantie");
-        
+
         Ok(Self {
             regret_sum: Arc::new(Mutex::new(HashMap::new())),
             strategy_sum: Arc::new(Mutex::new(HashMap::new())),
             equity_cache: Arc::new(HashMap::new().into()),
             config,
+            last_touched: Arc::new(Mutex::new(HashMap::new())),
             total_simulations: std::sync::atomic::AtomicU64::new(0),
             iterations: std::sync::atomic::AtomicUsize::new(0),
         })
@@ -75,8 +94,8 @@ impl RustCfrEngine {
         for i in 0..num_states {
             if let Ok(item) = py_states.get_item(i) {
                 if let Ok(py_dict) = item.downcast::<PyDict>() {
-                    if let Ok(convergence) = self.process_single_state(py_dict) {
-                        total_convergence += convergence;
+                    if let Ok(regret) = self.process_single_state(py_dict) {
+                        total_convergence += regret;
                     }
                 }
             }
@@ -122,38 +141,38 @@ impl RustCfrEngine {
         })
     }
 
-    /// 🔥 WIN PROBABILITY ULTRA-RAPIDE
+    /// 🔥 WIN PROBABILITY - vrai showdown Monte-Carlo sur cartes concrètes
     pub fn calculate_win_probability(&mut self, py_state: &PyDict, simulations: Option<usize>) -> PyResult<f64> {
         let sim_count = simulations.unwrap_or(10000);
-        let cache_key = self.create_cache_key(py_state);
-        
-        // Cache check ultra-rapide
+        let hole_cards = Self::extract_cards(py_state, "hole_cards");
+        let community_cards = Self::extract_cards(py_state, "community_cards");
+        let (_, _, _, num_players) = self.extract_state_values(py_state);
+
+        // Sans main héros concrète, pas de showdown possible: équité neutre
+        // plutôt qu'un tirage aléatoire sans rapport avec le jeu réel.
+        if hole_cards.len() < 2 {
+            return Ok(0.5);
+        }
+
+        let cache_key = Self::create_cache_key(&hole_cards, &community_cards, num_players);
+
+        // Cache check ultra-rapide: clé sur le set de cartes concret, donc
+        // l'équité mise en cache reste valide (pot/stack n'affectent pas le
+        // résultat d'un showdown).
         if let Ok(cache) = self.equity_cache.lock() {
             if let Some(&cached_prob) = cache.get(&cache_key) {
                 return Ok(cached_prob);
             }
         }
 
-        let (pot_size, stack_size, position, num_players) = self.extract_state_values(py_state);
-
-        // 🚀 SIMULATIONS MONTE CARLO ULTRA-RAPIDES
-        let mut wins = 0;
-        let mut rng = thread_rng();
-        
-        for _ in 0..sim_count {
-            if self.simulate_hand_ultra_fast(pot_size, stack_size, position, num_players, &mut rng) {
-                wins += 1;
-            }
-        }
+        let win_probability = Self::simulate_showdown_equity(&hole_cards, &community_cards, num_players, sim_count);
 
-        let win_probability = wins as f64 / sim_count as f64;
-        
         // Cache mise à jour thread-safe
         if let Ok(mut cache) = self.equity_cache.lock() {
             cache.insert(cache_key, win_probability);
         }
 
-        self.total_simulations.fetch_add(sim_count as u64, 
+        self.total_simulations.fetch_add(sim_count as u64,
             std::sync::atomic::Ordering::Relaxed);
 
         Ok(win_probability)
@@ -204,53 +223,119 @@ impl RustCfrEngine {
 
     // === MÉTHODES INTERNES ULTRA-OPTIMISÉES ===
 
-    fn process_single_state(&self, py_dict: &PyDict) -> PyResult<f64> {
+    /// Une itération de CFR à échantillonnage externe (MCCFR) sur
+    /// l'information set du state fourni. Ce snapshot plat ne porte pas de
+    /// sous-arbre récursif explicite (pas d'état enfant par action fourni
+    /// par Python) ; le noeud de hasard - distribution du board et main
+    /// adverse - est donc résolu par un petit nombre de tirages Monte-Carlo via
+    /// `calculate_win_probability`, ce qui garde un coût par itération
+    /// linéaire plutôt que d'énumérer exhaustivement les runouts. Le flag de
+    /// config `mccfr_enabled` sélectionne entre cet échantillonnage (peu de
+    /// tirages par appel) et un équivalent "vanilla" qui moyenne l'équité sur
+    /// davantage de tirages pour s'approcher de la vraie espérance.
+    fn process_single_state(&mut self, py_dict: &PyDict) -> PyResult<f64> {
         let info_set = self.extract_information_set(py_dict);
         let actions = self.get_legal_actions(&info_set);
-        
+
         if actions.is_empty() {
             return Ok(0.0);
         }
 
-        let (pot_size, stack_size, position, num_players) = self.extract_state_values(py_dict);
+        let (pot_size, _, _, _) = self.extract_state_values(py_dict);
 
-        // Calcul regrets pour chaque action
-        let mut regrets = Vec::new();
-        for action in &actions {
-            let regret = self.calculate_action_regret_heuristic(
-                action, pot_size, stack_size, position, num_players
-            );
-            regrets.push((action.clone(), regret));
-        }
+        let mccfr_enabled = self.config.get("mccfr_enabled").copied().unwrap_or(1.0) != 0.0;
+        let sim_count = if mccfr_enabled { 200 } else { 5000 };
+        let equity = self.calculate_win_probability(py_dict, Some(sim_count))?;
 
-        let total_regret: f64 = regrets.iter().map(|(_, r)| r.abs()).sum();
+        let current_strategy = {
+            let regret_sum = self.regret_sum.lock()
+                .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("regret_sum lock poisoned"))?;
+            Self::regret_matching(&actions, regret_sum.get(&info_set))
+        };
+
+        // Utilité contrefactuelle par action: vraie espérance de gain
+        // pondérée par l'équité mesurée (et non plus des multiplicateurs
+        // position/pot arbitraires).
+        let action_values: Vec<(String, f64)> = actions.iter()
+            .map(|action| (action.clone(), Self::action_ev(action, pot_size, equity)))
+            .collect();
+
+        let node_value: f64 = action_values.iter()
+            .map(|(action, value)| current_strategy.get(action).copied().unwrap_or(0.0) * value)
+            .sum();
+
+        let iteration = self.iterations.load(std::sync::atomic::Ordering::Relaxed) + 1;
+
+        // Itérations écoulées depuis la dernière visite de cet information
+        // set: Discounted CFR compose sa décote de chacune de ces itérations.
+        let elapsed = if let Ok(mut last_touched) = self.last_touched.lock() {
+            let previous = last_touched.insert(info_set.clone(), iteration);
+            previous.map(|last| iteration.saturating_sub(last)).unwrap_or(iteration)
+        } else {
+            iteration
+        };
 
-        // Mise à jour atomique des tables CFR
         if let Ok(mut regret_sum) = self.regret_sum.lock() {
             let info_regrets = regret_sum.entry(info_set.clone()).or_insert_with(HashMap::new);
-            
-            for (action, regret) in &regrets {
-                *info_regrets.entry(action.clone()).or_insert(0.0) += regret;
+            for (action, value) in &action_values {
+                let regret = value - node_value;
+                self.apply_regret_update(info_regrets, action, regret, elapsed);
             }
         }
 
-        // Mise à jour stratégies cumulées
         if let Ok(mut strategy_sum) = self.strategy_sum.lock() {
             let info_strategies = strategy_sum.entry(info_set).or_insert_with(HashMap::new);
-            
-            let total_positive_regret: f64 = regrets.iter()
-                .map(|(_, r)| r.max(0.0))
-                .sum();
-            
-            if total_positive_regret > 0.0 {
-                for (action, regret) in regrets {
-                    let prob = regret.max(0.0) / total_positive_regret;
-                    *info_strategies.entry(action).or_insert(0.0) += prob;
-                }
+            for (action, &prob) in &current_strategy {
+                self.apply_strategy_update(info_strategies, action, prob, iteration, elapsed);
             }
         }
 
-        Ok(total_regret)
+        // Regret instantané du meilleur écart à la stratégie courante: vrai
+        // proxy d'exploitabilité (tend vers 0 à l'équilibre), remplaçant la
+        // somme des |regrets| bruts qui ne reflétait pas la convergence.
+        let best_value = action_values.iter()
+            .map(|(_, v)| *v)
+            .fold(f64::NEG_INFINITY, f64::max);
+        Ok((best_value - node_value).max(0.0))
+    }
+
+    /// Stratégie courante par regret matching: proportionnelle aux regrets
+    /// positifs accumulés, uniforme si aucun regret positif.
+    fn regret_matching(actions: &[String], regrets: Option<&HashMap<String, f64>>) -> HashMap<String, f64> {
+        let positive: Vec<(String, f64)> = actions.iter()
+            .map(|a| (a.clone(), regrets.and_then(|r| r.get(a)).copied().unwrap_or(0.0).max(0.0)))
+            .collect();
+        let total: f64 = positive.iter().map(|(_, r)| r).sum();
+
+        let mut strategy = HashMap::new();
+        if total > 0.0 {
+            for (action, regret) in positive {
+                strategy.insert(action, regret / total);
+            }
+        } else {
+            let uniform = 1.0 / actions.len() as f64;
+            for action in actions {
+                strategy.insert(action.clone(), uniform);
+            }
+        }
+        strategy
+    }
+
+    /// Espérance de gain d'une action sachant l'équité mesurée: `fold` ne
+    /// coûte rien, les autres actions gagnent le pot (plus leur propre mise)
+    /// avec probabilité `equity` et perdent leur mise sinon.
+    fn action_ev(action: &str, pot_size: f64, equity: f64) -> f64 {
+        let cost = match action {
+            "fold" => return 0.0,
+            "check" => 0.0,
+            "call" => pot_size * 0.4,
+            "bet_small" => pot_size * 0.6,
+            "bet_medium" => pot_size * 0.8,
+            "bet_large" => pot_size * 1.0,
+            _ => pot_size * 0.3,
+        };
+
+        equity * (pot_size + cost) - (1.0 - equity) * cost
     }
 
     fn extract_state_values(&self, py_dict: &PyDict) -> (f64, f64, usize, usize) {
@@ -288,31 +373,84 @@ impl RustCfrEngine {
         format!("{}_{}_{}", position_bucket, betting_round, pot_bucket)
     }
 
-    fn create_cache_key(&self, py_dict: &PyDict) -> String {
-        let (pot_size, stack_size, position, num_players) = self.extract_state_values(py_dict);
-        format!("{}_{}_{}_{}", pot_size as u32, stack_size as u32, position, num_players)
+    /// Clé de cache sur le set de cartes concret (héros + board connu) plutôt
+    /// que pot/stack/position: deux states avec les mêmes cartes ont
+    /// exactement la même équité, quel que soit le pot.
+    fn create_cache_key(hole_cards: &[Card], community_cards: &[Card], num_players: usize) -> String {
+        let fmt_cards = |cards: &[Card]| -> String {
+            let mut sorted: Vec<Card> = cards.to_vec();
+            sorted.sort_unstable_by_key(|c| (c.rank, c.suit));
+            sorted.iter().map(|c| format!("{}-{}", c.rank, c.suit)).collect::<Vec<_>>().join(",")
+        };
+        format!("{}|{}|{}", fmt_cards(hole_cards), fmt_cards(community_cards), num_players)
+    }
+
+    /// Extrait une liste de cartes d'un champ `PyList` de tuples `(rank, suit)`
+    /// (même convention que `types::Card`). Absent ou mal formé -> liste vide.
+    fn extract_cards(py_dict: &PyDict, key: &str) -> Vec<Card> {
+        let Some(item) = py_dict.get_item(key) else { return Vec::new() };
+        let Ok(list) = item.downcast::<PyList>() else { return Vec::new() };
+
+        list.iter()
+            .filter_map(|entry| entry.extract::<(u8, u8)>().ok())
+            .map(|(rank, suit)| Card { rank, suit })
+            .collect()
     }
 
-    fn simulate_hand_ultra_fast(&self, pot_size: f64, stack_size: f64, 
-                               position: usize, num_players: usize, 
-                               rng: &mut ThreadRng) -> bool {
-        // Heuristique ultra-rapide vs simulation complète Python
-        let base_strength = rng.gen::<f64>() * 0.6 + 0.2; // 0.2-0.8
-        
-        let position_bonus = match position {
-            0..=2 => 0.0,      // Early position
-            3..=5 => 0.05,     // Middle position  
-            6..=9 => 0.1,      // Late position bonus
-            _ => 0.0,
-        };
-        
-        let pot_factor = (pot_size / stack_size).min(1.0) * 0.1;
-        let opponent_factor = (10.0 - num_players as f64) / 20.0;
-        
-        let hero_strength = base_strength + position_bonus + pot_factor + opponent_factor;
-        let avg_opponent_strength = rng.gen::<f64>() * 0.5 + 0.25;
-        
-        hero_strength > avg_opponent_strength
+    /// Showdown Monte-Carlo: complète le board manquant et distribue les
+    /// mains adverses depuis un deck excluant les cartes déjà connues,
+    /// évalue chaque main 7 cartes avec `hand_eval::evaluate_hand`, et
+    /// retourne l'équité moyenne sur `sim_count` tirages. Un split à
+    /// `k` adversaires à égalité avec le héros compte pour `1/(1+k)` plutôt
+    /// que 0 ou 1, donc la probabilité retournée expose déjà la fraction de
+    /// pot gagnée en cas d'égalité.
+    fn simulate_showdown_equity(
+        hole_cards: &[Card],
+        community_cards: &[Card],
+        num_players: usize,
+        sim_count: usize,
+    ) -> f64 {
+        let num_opponents = num_players.saturating_sub(1);
+        let missing_community = 5usize.saturating_sub(community_cards.len());
+
+        let mut known = hole_cards.to_vec();
+        known.extend_from_slice(community_cards);
+
+        let mut equity_sum = 0.0;
+        for _ in 0..sim_count {
+            let mut deck = Deck::new_random(&known);
+
+            let mut board = community_cards.to_vec();
+            for _ in 0..missing_community {
+                if let Some(card) = deck.deal() {
+                    board.push(card);
+                }
+            }
+
+            let mut hero_hand = hole_cards.to_vec();
+            hero_hand.extend_from_slice(&board);
+            let hero_rank = evaluate_hand(&hero_hand);
+
+            let mut hero_beats_all = true;
+            let mut tied_opponents = 0usize;
+            for _ in 0..num_opponents {
+                let mut opponent_hand: Vec<Card> = (0..2).filter_map(|_| deck.deal()).collect();
+                opponent_hand.extend_from_slice(&board);
+                let opponent_rank = evaluate_hand(&opponent_hand);
+
+                if opponent_rank > hero_rank {
+                    hero_beats_all = false;
+                } else if opponent_rank == hero_rank {
+                    tied_opponents += 1;
+                }
+            }
+
+            if hero_beats_all {
+                equity_sum += 1.0 / (1.0 + tied_opponents as f64);
+            }
+        }
+
+        equity_sum / sim_count as f64
     }
 
     fn get_legal_actions(&self, _info_set: &str) -> Vec<String> {
@@ -327,31 +465,68 @@ impl RustCfrEngine {
         ]
     }
 
-    fn calculate_action_regret_heuristic(&self, action: &str, pot_size: f64, 
-                                       stack_size: f64, position: usize, 
-                                       num_players: usize) -> f64 {
-        // Heuristique rapide pour regret (vs calcul exact Python lent)
-        let base_value = match action {
-            "fold" => 0.0,
-            "call" | "check" => pot_size * 0.4,
-            "bet_small" => pot_size * 0.6,
-            "bet_medium" => pot_size * 0.8,
-            "bet_large" => pot_size * 1.0,
-            _ => pot_size * 0.3,
-        };
-        
-        // Facteurs d'ajustement ultra-rapides
-        let position_factor = match position {
-            0..=2 => 0.9,  // Early position conservateur
-            3..=5 => 1.0,  // Middle position neutre
-            6..=9 => 1.1,  // Late position agressif
-            _ => 1.0,
-        };
-        
-        let stack_factor = (stack_size / pot_size).min(3.0) / 3.0;
-        let opponent_factor = (10.0 - num_players as f64) / 10.0;
-        
-        base_value * position_factor * (0.8 + stack_factor * 0.4) * (1.0 + opponent_factor * 0.2)
+    /// Met à jour le regret cumulé d'une action selon `update_rule` (lu
+    /// depuis `config`): 0=Vanilla (accumulation brute), 1=CFR+ (regrets
+    /// planchés à 0), 2=Discounted CFR (décote géométrique des regrets
+    /// existants, exposants `discount_alpha`/`discount_beta`, avant
+    /// d'appliquer la mise à jour). `elapsed` est le nombre d'itérations
+    /// écoulées depuis la dernière visite de cet information set.
+    fn apply_regret_update(&self, info_regrets: &mut HashMap<String, f64>, action: &str, regret: f64, elapsed: usize) {
+        let entry = info_regrets.entry(action.to_string()).or_insert(0.0);
+
+        match self.config.get("update_rule").copied().unwrap_or(0.0) as i32 {
+            1 => {
+                *entry = (*entry + regret).max(0.0);
+            }
+            2 => {
+                let alpha = self.config.get("discount_alpha").copied().unwrap_or(1.5);
+                let beta = self.config.get("discount_beta").copied().unwrap_or(0.0);
+                // Produit des décotes t^e/(t^e+1) des `elapsed` itérations
+                // écoulées, t = dernière visite + 1 ..= itération courante.
+                let iteration = self.iterations.load(std::sync::atomic::Ordering::Relaxed) + 1;
+                let first = iteration + 1 - elapsed.min(iteration);
+                let discount = |exponent: f64| -> f64 {
+                    (first..=iteration)
+                        .map(|k| { let t = (k as f64).powf(exponent); t / (t + 1.0) })
+                        .product()
+                };
+                if *entry > 0.0 {
+                    *entry *= discount(alpha);
+                } else if *entry < 0.0 {
+                    *entry *= discount(beta);
+                }
+                *entry += regret;
+            }
+            _ => {
+                *entry += regret;
+            }
+        }
+    }
+
+    /// Met à jour la stratégie cumulée d'une action selon `update_rule`:
+    /// 0=Vanilla (accumulation brute), 1=CFR+ (moyennage linéaire, pondéré
+    /// par le numéro d'itération globale `iteration`), 2=Discounted CFR
+    /// (décote géométrique par `discount_gamma` avant d'ajouter la
+    /// contribution, sur `elapsed` itérations depuis la dernière visite).
+    fn apply_strategy_update(&self, info_strategies: &mut HashMap<String, f64>, action: &str, probability: f64, iteration: usize, elapsed: usize) {
+        match self.config.get("update_rule").copied().unwrap_or(0.0) as i32 {
+            1 => {
+                *info_strategies.entry(action.to_string()).or_insert(0.0) += iteration as f64 * probability;
+            }
+            2 => {
+                let gamma = self.config.get("discount_gamma").copied().unwrap_or(2.0);
+                // Produit des (t/(t+1))^gamma pour t = dernière visite + 1
+                // ..= iteration, qui se télescope en (first/(iteration+1))^gamma.
+                let first = iteration + 1 - elapsed.min(iteration);
+                let factor = (first as f64 / (iteration + 1) as f64).powf(gamma);
+                let entry = info_strategies.entry(action.to_string()).or_insert(0.0);
+                *entry *= factor;
+                *entry += probability;
+            }
+            _ => {
+                *info_strategies.entry(action.to_string()).or_insert(0.0) += probability;
+            }
+        }
     }
 }
 
@@ -1,6 +1,31 @@
 /// Types de base pour le CFR engine
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
+
+/// `Duration` n'implémente pas `Serialize`/`Deserialize` en amont: on le
+/// réduit à des secondes fractionnaires, seule représentation dont un
+/// appelant JSON (config chargée depuis un fichier, export de stats) a
+/// besoin.
+mod duration_secs_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(value: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.map(|d| d.as_secs_f64()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let secs: Option<f64> = Option::deserialize(deserializer)?;
+        Ok(secs.map(Duration::from_secs_f64))
+    }
+}
 
 /// Représentation d'une carte
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -9,6 +34,89 @@ pub struct Card {
     pub suit: u8,  // 0-3 (Spades, Hearts, Diamonds, Clubs)
 }
 
+/// Jeu de 52 cartes pour un tirage sans remise et sans collision avec les
+/// cartes déjà connues (main héros, board déjà distribué).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// Construit un deck à partir de l'espace des 52 cartes en retirant
+    /// les cartes déjà connues.
+    pub fn new_excluding(known: &[Card]) -> Self {
+        let mut cards = Vec::with_capacity(52 - known.len());
+        for rank in 2..=14u8 {
+            for suit in 0..4u8 {
+                let card = Card { rank, suit };
+                if !known.contains(&card) {
+                    cards.push(card);
+                }
+            }
+        }
+        Self { cards }
+    }
+
+    /// Construit un deck mélangé aléatoirement (tirage non reproductible)
+    pub fn new_random(known: &[Card]) -> Self {
+        use rand::thread_rng;
+        let mut deck = Self::new_excluding(known);
+        deck.shuffle_with(&mut thread_rng());
+        deck
+    }
+
+    /// Construit un deck et le mélange avec une graine déterministe, pour
+    /// des runs d'entraînement reproductibles (voir `CfrConfig::seed`).
+    pub fn new_seeded(known: &[Card], seed: u64) -> Self {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut deck = Self::new_excluding(known);
+        deck.shuffle_with(&mut StdRng::seed_from_u64(seed));
+        deck
+    }
+
+    /// Construit un deck mélangé par un générateur déjà initialisé par
+    /// l'appelant, pour composer avec un seeding déterministe multi-étapes
+    /// (ex: un flux `ChaCha20Rng` dérivé par worker dans `CfrTrainer`) sans
+    /// ré-instancier un `StdRng` à chaque deck comme le ferait `new_seeded`.
+    pub fn new_with_rng(known: &[Card], rng: &mut impl rand::RngCore) -> Self {
+        let mut deck = Self::new_excluding(known);
+        deck.shuffle_with(rng);
+        deck
+    }
+
+    fn shuffle_with(&mut self, rng: &mut impl rand::RngCore) {
+        use rand::seq::SliceRandom;
+        self.cards.shuffle(rng);
+    }
+
+    /// Tire une carte sans remise (aucune collision possible)
+    pub fn deal(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Nombre de cartes restantes dans le deck
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Poids d'échantillonnage pour un tirage de `drawn` cartes parmi les
+    /// cartes restantes (1 / nombre de combinaisons), utilisé pour pondérer
+    /// l'importance des noeuds de hasard en MCCFR.
+    pub fn sampling_weight(&self, drawn: usize) -> f64 {
+        if drawn == 0 || self.cards.len() < drawn {
+            return 1.0;
+        }
+
+        let mut weight = 1.0;
+        let mut remaining = self.cards.len();
+        for k in 1..=drawn {
+            weight *= k as f64 / remaining as f64;
+            remaining -= 1;
+        }
+        weight
+    }
+}
+
 /// État d'une main de poker
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PokerState {
@@ -20,6 +128,11 @@ pub struct PokerState {
     pub num_players: usize,
     pub betting_round: BettingRound,
     pub available_actions: Vec<Action>,
+    /// Vrai si le joueur au trait a foldé (main terminale sans showdown)
+    pub folded: bool,
+    /// Deck partagé pour un run-out sans doublon ni collision avec les
+    /// cartes déjà connues. `None` dégrade vers un tirage indépendant.
+    pub deck: Option<Deck>,
 }
 
 /// Rounds de mise
@@ -43,7 +156,7 @@ pub enum Action {
 }
 
 /// Information set pour CFR
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InformationSet {
     pub abstracted_cards: u64,  // Hash des cartes abstraites
     pub betting_sequence: Vec<u8>,  // Séquence des actions
@@ -97,14 +210,45 @@ impl Strategy {
         strategy
     }
     
-    /// Update regrets after iteration
-    pub fn update_regret(&mut self, action: &Action, regret: f64) {
-        *self.regret_sum.entry(action.clone()).or_insert(0.0) += regret;
+    /// Update regrets after iteration, applying the configured update rule
+    /// (vanilla accumulation, CFR+ flooring, or Discounted-CFR decay).
+    pub fn update_regret(&mut self, action: &Action, regret: f64, params: &UpdateParams) {
+        let entry = self.regret_sum.entry(action.clone()).or_insert(0.0);
+        match params.rule {
+            CfrUpdateRule::Vanilla => {
+                *entry += regret;
+            }
+            CfrUpdateRule::CfrPlus => {
+                *entry = (*entry + regret).max(0.0);
+            }
+            CfrUpdateRule::Discounted => {
+                let t = params.iteration.max(1) as f64;
+                if *entry > 0.0 {
+                    *entry *= t.powf(params.alpha) / (t.powf(params.alpha) + 1.0);
+                } else if *entry < 0.0 {
+                    *entry *= t.powf(params.beta) / (t.powf(params.beta) + 1.0);
+                }
+                *entry += regret;
+            }
+        }
     }
-    
-    /// Update strategy sum for average strategy calculation
-    pub fn update_strategy(&mut self, action: &Action, probability: f64) {
-        *self.strategy_sum.entry(action.clone()).or_insert(0.0) += probability;
+
+    /// Update strategy sum for average strategy calculation, applying the
+    /// configured update rule. Linear averaging for CFR+ is expressed by the
+    /// caller scaling `probability` by the iteration count before calling.
+    pub fn update_strategy(&mut self, action: &Action, probability: f64, params: &UpdateParams) {
+        match params.rule {
+            CfrUpdateRule::Discounted => {
+                let t = params.iteration.max(1) as f64;
+                let factor = (t / (t + 1.0)).powf(params.gamma);
+                let entry = self.strategy_sum.entry(action.clone()).or_insert(0.0);
+                *entry *= factor;
+                *entry += probability;
+            }
+            CfrUpdateRule::Vanilla | CfrUpdateRule::CfrPlus => {
+                *self.strategy_sum.entry(action.clone()).or_insert(0.0) += probability;
+            }
+        }
     }
     
     /// Get average strategy over all iterations
@@ -142,6 +286,44 @@ impl Default for GpuConfig {
     }
 }
 
+/// Règle de mise à jour des regrets/stratégies
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CfrUpdateRule {
+    /// CFR classique: accumulation brute des regrets
+    Vanilla,
+    /// Regret-matching+: regrets cumulés planchés à 0, moyennage linéaire
+    CfrPlus,
+    /// Discounted CFR: décote géométrique des regrets/stratégie par itération
+    Discounted,
+}
+
+/// Paramètres de mise à jour résolus pour une itération donnée, construits
+/// depuis `CfrConfig` et passés à `Strategy::update_regret`/`update_strategy`.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateParams {
+    pub rule: CfrUpdateRule,
+    pub iteration: usize,
+    pub alpha: f64,
+    pub beta: f64,
+    pub gamma: f64,
+}
+
+/// Mode de parcours de l'arbre de jeu pendant l'entraînement
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SamplingMode {
+    /// Parcours complet de l'arbre à chaque itération (CFR vanilla)
+    FullTree,
+    /// Monte-Carlo CFR à échantillonnage externe: le traverseur explore
+    /// toutes ses actions, l'adversaire/le hasard sont échantillonnés.
+    ExternalSampling,
+    /// Monte-Carlo CFR à échantillonnage d'issue ("outcome sampling"): une
+    /// seule trajectoire racine-terminale est échantillonnée par itération
+    /// (mélange ε-exploratoire de la stratégie courante), et les
+    /// regrets/stratégie le long de ce chemin sont mis à jour via une
+    /// estimée pondérée par l'inverse des probabilités d'échantillonnage.
+    OutcomeSampling,
+}
+
 /// Configuration CFR
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CfrConfig {
@@ -151,6 +333,52 @@ pub struct CfrConfig {
     pub gpu_config: GpuConfig,
     pub abstraction_buckets: usize,
     pub sampling_enabled: bool,
+    /// Mode de parcours de l'arbre (parcours complet vs MCCFR échantillonné)
+    pub sampling: SamplingMode,
+    /// Règle de mise à jour des regrets (Vanilla / CFR+ / Discounted)
+    pub update_rule: CfrUpdateRule,
+    /// Discounted CFR: exposant de décote des regrets positifs
+    pub discount_alpha: f64,
+    /// Discounted CFR: exposant de décote des regrets négatifs
+    pub discount_beta: f64,
+    /// Discounted CFR: exposant de décote de la stratégie cumulée
+    pub discount_gamma: f64,
+    /// Graine déterministe pour des runs d'entraînement reproductibles:
+    /// consommée à la fois par `CfrTrainer` (génération de mains, deck) et
+    /// par `CfrEngine` (`sample_action`, repli sans-deck de
+    /// `deal_from_deck`), si bien qu'à seed fixée deux runs produisent des
+    /// batches et des traces de convergence identiques. `None` = aléatoire
+    /// non reproductible.
+    pub seed: Option<u64>,
+    /// Budget de temps mur-à-mur optionnel: `CfrTrainer::start_training`
+    /// s'arrête dès qu'il est épuisé (comportement "anytime"), en plus de
+    /// `max_iterations`/`convergence_threshold`. `None` = pas de limite.
+    #[serde(with = "duration_secs_option")]
+    pub time_budget: Option<Duration>,
+    /// Taux d'exploration ε initial du mélange ε-exploratoire appliqué à
+    /// l'échantillonnage adverse/hasard (voir `CfrEngine::sample_action`):
+    /// haut en début de run pour un échantillonnage large, refroidi vers un
+    /// plancher à mesure que la progression avance (voir
+    /// `exploration_cooling_rate`).
+    pub exploration_rate: f64,
+    /// Taux de refroidissement géométrique de `exploration_rate`, le même
+    /// rôle que le `discount_factor` d'un recuit simulé: le taux courant vaut
+    /// `floor + (exploration_rate - floor) * exploration_cooling_rate.powf(progress)`
+    /// où `progress` va de 0 à 1 sur la durée du run.
+    pub exploration_cooling_rate: f64,
+}
+
+impl CfrConfig {
+    /// Résout les paramètres de mise à jour pour l'itération courante
+    pub fn update_params(&self, iteration: usize) -> UpdateParams {
+        UpdateParams {
+            rule: self.update_rule,
+            iteration,
+            alpha: self.discount_alpha,
+            beta: self.discount_beta,
+            gamma: self.discount_gamma,
+        }
+    }
 }
 
 impl Default for CfrConfig {
@@ -162,6 +390,41 @@ impl Default for CfrConfig {
             gpu_config: GpuConfig::default(),
             abstraction_buckets: 64,
             sampling_enabled: true,
+            sampling: SamplingMode::FullTree,
+            update_rule: CfrUpdateRule::Vanilla,
+            discount_alpha: 1.5,
+            discount_beta: 0.0,
+            discount_gamma: 2.0,
+            seed: None,
+            time_budget: None,
+            exploration_rate: 0.3,
+            exploration_cooling_rate: 0.05,
         }
     }
+}
+
+/// Version du format de snapshot, à incrémenter à chaque changement de
+/// schéma binaire/JSON incompatible.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// En-tête de snapshot: permet de valider la compatibilité d'un import avant
+/// de fusionner les stratégies (version de format, config d'abstraction) et
+/// de restaurer l'avancement exact de l'entraînement (itérations, convergence).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotHeader {
+    pub format_version: u32,
+    pub crate_version: String,
+    pub iterations: usize,
+    pub convergence_metric: f64,
+    pub abstraction_buckets: usize,
+}
+
+/// Snapshot complet d'un entraînement CFR: en-tête de validation plus toutes
+/// les stratégies par information set, sérialisées canoniquement (round-trip
+/// exact en JSON comme en binaire, contrairement à l'ancien export en clés
+/// `{:?}`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub header: SnapshotHeader,
+    pub strategies: Vec<(InformationSet, Strategy)>,
 }
\ No newline at end of file